@@ -0,0 +1,136 @@
+use clap::{Parser, Subcommand};
+use origa::domain::{import_jmdict, import_kanjidic2, import_pitch_accents};
+use std::path::{Path, PathBuf};
+
+const CHUNKS_COUNT: usize = 10;
+
+#[derive(Parser)]
+#[command(name = "jmdict_importer")]
+#[command(about = "Сборка встроенных словарей из JMdict, KANJIDIC2 и таблицы ударений", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Дополнить чанки словаря слов из JMdict
+    Jmdict {
+        /// Путь к файлу JMdict
+        jmdict: PathBuf,
+
+        /// Каталог с чанками словаря
+        #[arg(short, long, default_value = "origa/src/domain/dictionary/vocabulary")]
+        vocabulary: PathBuf,
+    },
+    /// Собрать чтения кандзи из KANJIDIC2
+    Kanjidic2 {
+        /// Путь к файлу KANJIDIC2
+        kanjidic2: PathBuf,
+
+        /// Файл с чтениями кандзи
+        #[arg(
+            short,
+            long,
+            default_value = "origa/src/domain/dictionary/kanjidic2.json"
+        )]
+        output: PathBuf,
+    },
+    /// Заполнить ударения слов в чанках словаря из accents.txt Kanjium
+    Accents {
+        /// Путь к файлу ударений
+        accents: PathBuf,
+
+        /// Каталог с чанками словаря
+        #[arg(short, long, default_value = "origa/src/domain/dictionary/vocabulary")]
+        vocabulary: PathBuf,
+    },
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Jmdict { jmdict, vocabulary } => run_jmdict(&jmdict, &vocabulary),
+        Command::Kanjidic2 { kanjidic2, output } => run_kanjidic2(&kanjidic2, &output),
+        Command::Accents {
+            accents,
+            vocabulary,
+        } => run_accents(&accents, &vocabulary),
+    }
+}
+
+fn run_jmdict(jmdict: &Path, vocabulary: &Path) {
+    let xml = read(jmdict);
+
+    let chunk_paths = chunk_paths(vocabulary);
+    let existing_chunks: Vec<String> = chunk_paths
+        .iter()
+        .filter(|path| path.exists())
+        .map(|path| read(path))
+        .collect();
+    let existing_chunks: Vec<&str> = existing_chunks.iter().map(String::as_str).collect();
+
+    let chunks = import_jmdict(&xml, &existing_chunks, CHUNKS_COUNT).unwrap_or_else(|e| {
+        eprintln!("Ошибка импорта: {}", e);
+        std::process::exit(1);
+    });
+
+    for (path, chunk) in chunk_paths.iter().zip(chunks) {
+        write(path, &chunk);
+    }
+
+    println!("Словарь записан в {}", vocabulary.display());
+}
+
+fn run_accents(accents: &Path, vocabulary: &Path) {
+    let accents = read(accents);
+
+    let chunk_paths: Vec<_> = chunk_paths(vocabulary)
+        .into_iter()
+        .filter(|path| path.exists())
+        .collect();
+    let existing_chunks: Vec<String> = chunk_paths.iter().map(|path| read(path)).collect();
+    let existing_chunks: Vec<&str> = existing_chunks.iter().map(String::as_str).collect();
+
+    let chunks = import_pitch_accents(&accents, &existing_chunks).unwrap_or_else(|e| {
+        eprintln!("Ошибка импорта: {}", e);
+        std::process::exit(1);
+    });
+
+    for (path, chunk) in chunk_paths.iter().zip(chunks) {
+        write(path, &chunk);
+    }
+
+    println!("Ударения записаны в {}", vocabulary.display());
+}
+
+fn run_kanjidic2(kanjidic2: &Path, output: &Path) {
+    let xml = read(kanjidic2);
+
+    let kanjidic = import_kanjidic2(&xml).unwrap_or_else(|e| {
+        eprintln!("Ошибка импорта: {}", e);
+        std::process::exit(1);
+    });
+
+    write(output, &kanjidic);
+    println!("Чтения кандзи записаны в {}", output.display());
+}
+
+fn chunk_paths(vocabulary: &Path) -> Vec<PathBuf> {
+    (1..=CHUNKS_COUNT)
+        .map(|i| vocabulary.join(format!("chunk_{:02}.json", i)))
+        .collect()
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Ошибка чтения файла {}: {}", path.display(), e);
+        std::process::exit(1);
+    })
+}
+
+fn write(path: &Path, content: &str) {
+    if let Err(e) = std::fs::write(path, content) {
+        eprintln!("Ошибка записи файла {}: {}", path.display(), e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,36 @@
+use std::{env, fs, path::Path};
+
+/// Встраивает анимации порядка черт кандзи: для каждого SVG из
+/// `kanji_animations` генерируется запись в отсортированной по кандзи таблице
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let animations_dir = Path::new(&manifest_dir).join("src/domain/dictionary/kanji_animations");
+    println!("cargo:rerun-if-changed={}", animations_dir.display());
+
+    let mut animations = fs::read_dir(&animations_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "svg"))
+        .filter_map(|path| {
+            let mut chars = path.file_stem()?.to_str()?.chars();
+            match (chars.next(), chars.next()) {
+                (Some(kanji), None) => Some((kanji, path)),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    animations.sort();
+
+    let mut table = String::from("&[\n");
+    for (kanji, path) in animations {
+        table.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            kanji,
+            path.display().to_string()
+        ));
+    }
+    table.push_str("]\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("kanji_animations.rs"), table).unwrap();
+}
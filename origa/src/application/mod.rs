@@ -8,6 +8,6 @@ pub mod user_repository;
 pub use duolingo_client::{DuolingoClient, DuolingoWord};
 pub use llm_service::LlmService;
 pub use migii_client::{MigiiClient, MigiiMeaning, MigiiWord};
-pub use srs_service::{NextReview, RateMode, SrsService};
+pub use srs_service::{ForecastMode, NextReview, RateMode, SrsService, WorkloadDay};
 pub use use_cases::*;
 pub use user_repository::UserRepository;
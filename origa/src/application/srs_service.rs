@@ -1,12 +1,28 @@
 use crate::domain::OrigaError;
-use crate::domain::{MemoryHistory, MemoryState, Rating};
-use chrono::Duration;
+use crate::domain::{MemoryHistory, MemoryState, Rating, SrsParameters};
+use chrono::{Duration, NaiveDate};
 
 pub struct NextReview {
     pub interval: Duration,
     pub memory_state: MemoryState,
 }
 
+/// Прогноз нагрузки на один день
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkloadDay {
+    pub date: NaiveDate,
+    pub reviews: usize,
+    pub new_cards: usize,
+}
+
+/// Способ построить прогноз нагрузки
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ForecastMode {
+    #[default]
+    Simulation,
+    Projection,
+}
+
 pub enum RateMode {
     StandardLesson,
     FixationLesson,
@@ -21,5 +37,35 @@ pub trait SrsService: Send + Sync {
         mode: RateMode,
         rating: Rating,
         memory_history: &MemoryHistory,
+        parameters: Option<&SrsParameters>,
     ) -> Result<NextReview, OrigaError>;
+
+    async fn optimize(
+        &self,
+        memory_histories: &[&MemoryHistory],
+    ) -> Result<SrsParameters, OrigaError>;
+
+    /// Моделирует повторения существующих карточек и `new_cards` новых,
+    /// которые вводятся по `new_cards_per_day` в день. Забывания выбираются
+    /// случайно по вероятности вспомнить, результат усредняется по нескольким
+    /// прогонам с фиксированными зернами
+    async fn simulate(
+        &self,
+        memory_histories: &[&MemoryHistory],
+        new_cards: usize,
+        new_cards_per_day: usize,
+        days: usize,
+        parameters: Option<&SrsParameters>,
+    ) -> Result<Vec<WorkloadDay>, OrigaError>;
+
+    /// Прогноз без случайности: каждая карточка повторяется в назначенный день
+    /// и вспоминается, следующий срок считается по ее стабильности
+    async fn project(
+        &self,
+        memory_histories: &[&MemoryHistory],
+        new_cards: usize,
+        new_cards_per_day: usize,
+        days: usize,
+        parameters: Option<&SrsParameters>,
+    ) -> Result<Vec<WorkloadDay>, OrigaError>;
 }
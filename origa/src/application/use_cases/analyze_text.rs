@@ -0,0 +1,25 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::{OrigaError, TextAnalysis};
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct AnalyzeTextUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> AnalyzeTextUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Сравнивает слова текста с карточками пользователя и оценивает уровень текста
+    pub async fn execute(&self, user_id: Ulid, text: &str) -> Result<TextAnalysis, OrigaError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        TextAnalysis::analyze(text, user.knowledge_set())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::{AnswerCheck, CardVariant, OrigaError};
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct CheckAnswerUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> CheckAnswerUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Проверяет ответ для карточки в том варианте, в котором она была показана
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        variant: CardVariant,
+        typed_answer: &str,
+    ) -> Result<AnswerCheck, OrigaError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let study_card = user
+            .knowledge_set()
+            .get_card(card_id)
+            .ok_or(OrigaError::CardNotFound { card_id })?;
+
+        let lesson_card = study_card.lesson_card(variant, user.native_language())?;
+
+        Ok(lesson_card.card().check_answer(typed_answer))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::{Card, HANDWRITING_RECOGNIZER, OrigaError, StrokePoint, WritingCheck};
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct CheckWritingUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> CheckWritingUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Проверяет кандзи, написанный пользователем для карточки кандзи
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        strokes: &[Vec<StrokePoint>],
+    ) -> Result<WritingCheck, OrigaError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let study_card = user
+            .knowledge_set()
+            .get_card(card_id)
+            .ok_or(OrigaError::CardNotFound { card_id })?;
+
+        let Card::Kanji(kanji_card) = study_card.card() else {
+            return Err(OrigaError::InvalidValues {
+                reason: "Writing can be checked only for kanji cards".to_string(),
+            });
+        };
+
+        HANDWRITING_RECOGNIZER.check_writing(kanji_card.kanji().text(), strokes)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::application::UserRepository;
+use crate::domain::OrigaError;
+use crate::domain::{Answer, Card, Question, SentenceCard, StudyCard};
+use tracing::error;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct CreateSentenceCardsUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> CreateSentenceCardsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Создает карточки предложений из примеров фраз карточки словаря
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        vocabulary_card_id: Ulid,
+    ) -> Result<Vec<StudyCard>, OrigaError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let study_card =
+            user.knowledge_set()
+                .get_card(vocabulary_card_id)
+                .ok_or(OrigaError::CardNotFound {
+                    card_id: vocabulary_card_id,
+                })?;
+
+        let Card::Vocabulary(vocabulary_card) = study_card.card() else {
+            return Err(OrigaError::InvalidValues {
+                reason: "Sentence cards can be created only from vocabulary cards".to_string(),
+            });
+        };
+
+        let example_phrases = vocabulary_card.example_phrases().to_vec();
+
+        let mut cards = Vec::new();
+        for phrase in example_phrases {
+            let sentence_card = SentenceCard::new(
+                Question::new(phrase.text().to_string())?,
+                Answer::new(phrase.translation().to_string())?,
+            )?;
+
+            match user.create_card(Card::Sentence(sentence_card)) {
+                Ok(card) => cards.push(card),
+                Err(err) => error!("Failed to create sentence card: {}", err),
+            }
+        }
+
+        self.repository.save(&user).await?;
+
+        Ok(cards)
+    }
+}
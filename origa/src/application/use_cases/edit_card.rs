@@ -0,0 +1,43 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::{Answer, ExamplePhrase, OrigaError, Question, StudyCard};
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct EditCardUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+#[derive(Clone, Default)]
+pub struct EditCardRequest {
+    pub question: Option<String>,
+    pub answer: Option<String>,
+    pub example_phrases: Option<Vec<ExamplePhrase>>,
+}
+
+impl<'a, R: UserRepository> EditCardUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        card_id: Ulid,
+        request: EditCardRequest,
+    ) -> Result<StudyCard, OrigaError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let question = request.question.map(Question::new).transpose()?;
+        let answer = request.answer.map(Answer::new).transpose()?;
+
+        let study_card = user.edit_card(card_id, question, answer, request.example_phrases)?;
+
+        self.repository.save(&user).await?;
+
+        Ok(study_card)
+    }
+}
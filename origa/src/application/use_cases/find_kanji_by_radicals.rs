@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use crate::domain::{KANJI_DICTIONARY, KanjiInfo, OrigaError, RADICAL_DICTIONARY};
+
+pub struct FindKanjiByRadicalsUseCase;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanjiByRadicals {
+    /// Кандзи по возрастанию числа черт, при равенстве — от простого уровня к сложному
+    pub kanji: Vec<KanjiInfo>,
+    /// Радикалы, добавление которых оставит хотя бы один кандзи
+    pub valid_radicals: Vec<char>,
+}
+
+impl Default for FindKanjiByRadicalsUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FindKanjiByRadicalsUseCase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Ищет кандзи, в которых есть все выбранные радикалы
+    pub fn execute(&self, radicals: &[char]) -> Result<KanjiByRadicals, OrigaError> {
+        let mut kanji: Vec<KanjiInfo> = RADICAL_DICTIONARY
+            .kanji_with_radicals(radicals)?
+            .into_iter()
+            .filter_map(|k| KANJI_DICTIONARY.get_kanji_info(&k.to_string()).ok())
+            .cloned()
+            .collect();
+        kanji.sort_by_key(|info| (info.stroke_count(), *info.jlpt(), info.kanji()));
+
+        let found: HashSet<char> = kanji.iter().map(|info| info.kanji()).collect();
+        let mut valid_radicals: Vec<_> = RADICAL_DICTIONARY
+            .known_radicals()
+            .iter()
+            .filter(|radical| !radicals.contains(radical))
+            .filter_map(|radical| RADICAL_DICTIONARY.get_radical_info(radical).ok())
+            .filter(|info| info.kanji().iter().any(|k| found.contains(k)))
+            .collect();
+        valid_radicals.sort_by_key(|info| (info.stroke_count(), info.radical()));
+
+        Ok(KanjiByRadicals {
+            kanji,
+            valid_radicals: valid_radicals.iter().map(|info| info.radical()).collect(),
+        })
+    }
+}
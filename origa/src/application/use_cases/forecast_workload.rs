@@ -0,0 +1,92 @@
+use crate::application::user_repository::UserRepository;
+use crate::application::{ForecastMode, SrsService, WorkloadDay};
+use crate::domain::{OrigaError, WellKnownSets, load_well_known_set};
+use ulid::Ulid;
+
+#[derive(Clone, Copy)]
+pub struct ForecastWorkloadUseCase<'a, R: UserRepository, S: SrsService> {
+    repository: &'a R,
+    srs_service: &'a S,
+}
+
+#[derive(Clone)]
+pub struct ForecastWorkloadRequest {
+    pub days: usize,
+    /// По умолчанию берется из плана занятий пользователя
+    pub new_cards_per_day: Option<usize>,
+    pub additional_new_cards: usize,
+    /// Набор, импорт которого нужно смоделировать
+    pub well_known_set: Option<WellKnownSets>,
+    pub mode: ForecastMode,
+}
+
+impl<'a, R: UserRepository, S: SrsService> ForecastWorkloadUseCase<'a, R, S> {
+    pub fn new(repository: &'a R, srs_service: &'a S) -> Self {
+        Self {
+            repository,
+            srs_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        request: ForecastWorkloadRequest,
+    ) -> Result<Vec<WorkloadDay>, OrigaError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let study_cards: Vec<_> = user
+            .knowledge_set()
+            .study_cards()
+            .values()
+            .filter(|card| !card.is_suspended())
+            .collect();
+
+        let memory_histories: Vec<_> = study_cards.iter().map(|card| card.memory()).collect();
+
+        let well_known_set_size = match &request.well_known_set {
+            Some(set) => load_well_known_set(set)?.words().len(),
+            None => 0,
+        };
+        let new_cards = study_cards
+            .iter()
+            .filter(|card| card.memory().is_new())
+            .count()
+            + request.additional_new_cards
+            + well_known_set_size;
+
+        let new_cards_per_day = request
+            .new_cards_per_day
+            .unwrap_or(user.settings().study_plan().new_cards_per_day());
+
+        let parameters = user.settings().srs_parameters();
+        match request.mode {
+            ForecastMode::Simulation => {
+                self.srs_service
+                    .simulate(
+                        &memory_histories,
+                        new_cards,
+                        new_cards_per_day,
+                        request.days,
+                        parameters,
+                    )
+                    .await
+            }
+            ForecastMode::Projection => {
+                self.srs_service
+                    .project(
+                        &memory_histories,
+                        new_cards,
+                        new_cards_per_day,
+                        request.days,
+                        parameters,
+                    )
+                    .await
+            }
+        }
+    }
+}
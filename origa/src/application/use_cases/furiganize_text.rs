@@ -0,0 +1,29 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::{FuriganaSegment, OrigaError, furiganize_for_user};
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct FuriganizeTextUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> FuriganizeTextUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Фуригана над кандзи, которые пользователь еще не выучил
+    pub async fn execute(
+        &self,
+        user_id: Ulid,
+        text: &str,
+    ) -> Result<Vec<FuriganaSegment>, OrigaError> {
+        let user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        furiganize_for_user(text, &user)
+    }
+}
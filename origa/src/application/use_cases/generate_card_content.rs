@@ -48,14 +48,11 @@ impl<'a, L: LlmService> GenerateCardContentUseCase<'a, L> {
         question_text: &str,
         native_language: &NativeLanguage,
     ) -> Result<Option<CardContent>, OrigaError> {
-        if let Some(translation) =
-            VOCABULARY_DICTIONARY.get_translation(question_text, native_language)
-        {
-            let answer = Answer::new(translation)?;
-            // TODO: EXAMPLES!!
+        if let Some(info) = VOCABULARY_DICTIONARY.get_vocabulary_info(question_text) {
+            let answer = Answer::new(info.translation(native_language).to_string())?;
             return Ok(Some(CardContent {
                 answer,
-                examples: vec![],
+                examples: info.examples(native_language).to_vec(),
             }));
         }
 
@@ -1,8 +1,16 @@
+mod analyze_text;
+mod check_answer;
+mod check_writing;
 mod complete_lesson;
 mod create_grammar_card;
 mod create_kanji_card;
+mod create_sentence_cards;
 mod create_vocabulary_card;
 mod delete_card;
+mod edit_card;
+mod find_kanji_by_radicals;
+mod forecast_workload;
+mod furiganize_text;
 mod generate_card_content;
 mod get_user_info;
 mod grammar_info;
@@ -12,19 +20,34 @@ mod import_well_known_set;
 mod kanji_info;
 mod kanji_list;
 mod knowledge_set_cards;
+mod list_leeches;
 mod list_well_known_sets;
+mod optimize_srs_parameters;
 mod rate_card;
+mod recognize_handwriting;
+mod reset_card;
 mod select_cards_to_fixation;
 mod select_cards_to_lesson;
+mod suspend_card;
 mod sync_duolingo_words;
+mod undo_last_rating;
+mod unsuspend_card;
 mod update_user_profile;
 mod update_user_settings;
 
+pub use analyze_text::*;
+pub use check_answer::*;
+pub use check_writing::*;
 pub use complete_lesson::*;
 pub use create_grammar_card::*;
 pub use create_kanji_card::*;
+pub use create_sentence_cards::*;
 pub use create_vocabulary_card::*;
 pub use delete_card::*;
+pub use edit_card::*;
+pub use find_kanji_by_radicals::*;
+pub use forecast_workload::*;
+pub use furiganize_text::*;
 pub use generate_card_content::*;
 pub use get_user_info::*;
 pub use grammar_info::*;
@@ -34,10 +57,17 @@ pub use import_well_known_set::*;
 pub use kanji_info::*;
 pub use kanji_list::*;
 pub use knowledge_set_cards::*;
+pub use list_leeches::*;
 pub use list_well_known_sets::*;
+pub use optimize_srs_parameters::*;
 pub use rate_card::*;
+pub use recognize_handwriting::*;
+pub use reset_card::*;
 pub use select_cards_to_fixation::*;
 pub use select_cards_to_lesson::*;
+pub use suspend_card::*;
 pub use sync_duolingo_words::*;
+pub use undo_last_rating::*;
+pub use unsuspend_card::*;
 pub use update_user_profile::*;
 pub use update_user_settings::*;
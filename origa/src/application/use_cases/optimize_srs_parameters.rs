@@ -0,0 +1,48 @@
+use crate::application::SrsService;
+use crate::application::user_repository::UserRepository;
+use crate::domain::OrigaError;
+use crate::domain::SrsParameters;
+use ulid::Ulid;
+
+#[derive(Clone, Copy)]
+pub struct OptimizeSrsParametersUseCase<'a, R: UserRepository, S: SrsService> {
+    repository: &'a R,
+    srs_service: &'a S,
+}
+
+impl<'a, R: UserRepository, S: SrsService> OptimizeSrsParametersUseCase<'a, R, S> {
+    pub fn new(repository: &'a R, srs_service: &'a S) -> Self {
+        Self {
+            repository,
+            srs_service,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Ulid) -> Result<SrsParameters, OrigaError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let memory_histories: Vec<_> = user
+            .knowledge_set()
+            .study_cards()
+            .values()
+            .map(|card| card.memory())
+            .collect();
+
+        let parameters = self.srs_service.optimize(&memory_histories).await?;
+
+        user.settings_mut()
+            .set_srs_parameters(Some(parameters.clone()));
+
+        self.repository.save(&user).await?;
+
+        println!(
+            "Finished optimizing SRS parameters on {} reviews",
+            parameters.reviews_count()
+        );
+        Ok(parameters)
+    }
+}
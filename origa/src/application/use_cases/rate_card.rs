@@ -2,7 +2,8 @@ use crate::application::SrsService;
 use crate::application::srs_service::{NextReview, RateMode};
 use crate::application::user_repository::UserRepository;
 use crate::domain::OrigaError;
-use crate::domain::Rating;
+use crate::domain::{CardVariant, Rating, ReviewLog, ReviewMode};
+use chrono::Duration;
 use ulid::Ulid;
 
 #[derive(Clone, Copy)]
@@ -25,6 +26,8 @@ impl<'a, R: UserRepository, S: SrsService> RateCardUseCase<'a, R, S> {
         card_id: Ulid,
         mode: RateMode,
         rating: Rating,
+        variant: CardVariant,
+        response_time: Option<Duration>,
     ) -> Result<(), OrigaError> {
         let mut user = self
             .repository
@@ -37,12 +40,26 @@ impl<'a, R: UserRepository, S: SrsService> RateCardUseCase<'a, R, S> {
             .get_card(card_id)
             .ok_or(OrigaError::CardNotFound { card_id })?;
 
+        let review_mode = match mode {
+            RateMode::StandardLesson => ReviewMode::Standard,
+            RateMode::FixationLesson => ReviewMode::Fixation,
+        };
+
         let NextReview {
             interval,
             memory_state,
-        } = self.srs_service.rate(mode, rating, card.memory()).await?;
+        } = self
+            .srs_service
+            .rate(
+                mode,
+                rating,
+                card.memory(),
+                user.settings().srs_parameters(),
+            )
+            .await?;
 
-        user.rate_card(card_id, rating, interval, memory_state)?;
+        let review = ReviewLog::new(rating, interval, review_mode, variant, response_time);
+        user.rate_card(card_id, memory_state, review)?;
 
         self.repository.save(&user).await?;
 
@@ -0,0 +1,24 @@
+use crate::domain::{HANDWRITING_RECOGNIZER, HandwritingCandidate, OrigaError, StrokePoint};
+
+pub struct RecognizeHandwritingUseCase;
+
+impl Default for RecognizeHandwritingUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecognizeHandwritingUseCase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Ищет кандзи по чертам, нарисованным пользователем
+    pub fn execute(
+        &self,
+        strokes: &[Vec<StrokePoint>],
+        limit: usize,
+    ) -> Result<Vec<HandwritingCandidate>, OrigaError> {
+        HANDWRITING_RECOGNIZER.recognize(strokes, limit)
+    }
+}
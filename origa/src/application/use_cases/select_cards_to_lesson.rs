@@ -1,7 +1,6 @@
 use crate::application::user_repository::UserRepository;
-use crate::domain::Card;
+use crate::domain::LessonCard;
 use crate::domain::OrigaError;
-use std::collections::HashMap;
 use ulid::Ulid;
 
 #[derive(Clone)]
@@ -14,7 +13,7 @@ impl<'a, R: UserRepository> SelectCardsToLessonUseCase<'a, R> {
         Self { repository }
     }
 
-    pub async fn execute(&self, user_id: Ulid) -> Result<HashMap<Ulid, Card>, OrigaError> {
+    pub async fn execute(&self, user_id: Ulid) -> Result<Vec<LessonCard>, OrigaError> {
         let user = self
             .repository
             .find_by_id(user_id)
@@ -22,6 +21,8 @@ impl<'a, R: UserRepository> SelectCardsToLessonUseCase<'a, R> {
             .ok_or(OrigaError::UserNotFound { user_id })?;
 
         println!("Selected cards to lesson");
-        Ok(user.knowledge_set().cards_to_lesson(user.native_language()))
+        Ok(user
+            .knowledge_set()
+            .cards_to_lesson(user.native_language(), user.settings().study_plan()))
     }
 }
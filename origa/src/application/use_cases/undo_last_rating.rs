@@ -0,0 +1,30 @@
+use crate::application::user_repository::UserRepository;
+use crate::domain::OrigaError;
+use ulid::Ulid;
+
+#[derive(Clone)]
+pub struct UndoLastRatingUseCase<'a, R: UserRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: UserRepository> UndoLastRatingUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// Возвращает идентификатор карточки, оценка которой была отменена
+    pub async fn execute(&self, user_id: Ulid) -> Result<Ulid, OrigaError> {
+        let mut user = self
+            .repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(OrigaError::UserNotFound { user_id })?;
+
+        let card_id = user.undo_last_rating()?;
+
+        self.repository.save(&user).await?;
+
+        println!("Undone last rating for card {}", card_id);
+        Ok(card_id)
+    }
+}
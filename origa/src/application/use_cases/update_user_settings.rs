@@ -1,6 +1,7 @@
 use crate::application::UserRepository;
 use crate::domain::LlmSettings;
 use crate::domain::OrigaError;
+use crate::domain::StudyPlan;
 use ulid::Ulid;
 
 #[derive(Clone)]
@@ -12,6 +13,7 @@ pub struct UpdateUserSettingsUseCase<'a, R: UserRepository> {
 pub struct UpdateUserSettingsRequest {
     pub llm: Option<LlmSettings>,
     pub duolingo_jwt_token: Option<Option<String>>,
+    pub study_plan: Option<StudyPlan>,
 }
 
 impl<'a, R: UserRepository> UpdateUserSettingsUseCase<'a, R> {
@@ -40,6 +42,10 @@ impl<'a, R: UserRepository> UpdateUserSettingsUseCase<'a, R> {
             settings.set_duolingo_jwt_token(duolingo_jwt_token);
         }
 
+        if let Some(study_plan) = request.study_plan {
+            settings.set_study_plan(study_plan);
+        }
+
         self.repository.save(&user).await?;
         Ok(())
     }
@@ -0,0 +1,286 @@
+use crate::domain::{
+    Rating,
+    japanese::JapaneseText,
+    romaji::{is_romaji, long_e_as_ei, long_o_as_oo, romaji_to_hiragana},
+};
+
+const ANSWER_SEPARATORS: [char; 4] = [',', ';', '、', '/'];
+const TRAILING_PUNCTUATION: [char; 6] = ['.', '!', '?', '。', '！', '？'];
+const TYPO_SIMILARITY_THRESHOLD: f64 = 0.8;
+
+/// Результат проверки введенного ответа
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnswerCheck {
+    is_correct: bool,
+    similarity: f64,
+    diff: Vec<CharDiff>,
+}
+
+impl AnswerCheck {
+    pub fn is_correct(&self) -> bool {
+        self.is_correct
+    }
+
+    /// Похожесть ответа на ближайший правильный вариант от 0 до 1
+    pub fn similarity(&self) -> f64 {
+        self.similarity
+    }
+
+    /// Посимвольное сравнение с ближайшим правильным вариантом
+    pub fn diff(&self) -> &[CharDiff] {
+        &self.diff
+    }
+
+    /// Оценка, которую стоит предложить пользователю
+    pub fn suggested_rating(&self) -> Rating {
+        if self.is_correct {
+            Rating::Good
+        } else if self.similarity >= TYPO_SIMILARITY_THRESHOLD {
+            Rating::Hard
+        } else {
+            Rating::Again
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharDiff {
+    /// Символ совпал
+    Equal(char),
+    /// Символ есть в правильном ответе, но не был введен
+    Missing(char),
+    /// Лишний введенный символ
+    Extra(char),
+}
+
+/// Сравнивает введенный ответ со списком правильных. Каждый правильный ответ
+/// может содержать несколько вариантов через запятую или точку с запятой.
+/// Ответ, набранный ромадзи, сравнивается с японскими вариантами как кана.
+pub fn check_answer(expected: &[String], typed: &str) -> AnswerCheck {
+    let alternatives: Vec<String> = expected
+        .iter()
+        .flat_map(|answer| std::iter::once(answer.as_str()).chain(answer.split(ANSWER_SEPARATORS)))
+        .map(normalize)
+        .filter(|answer| !answer.is_empty())
+        .collect();
+
+    let typed = normalize(typed);
+    let typed_variants = if is_romaji(&typed)
+        && !alternatives.contains(&typed)
+        && alternatives.iter().any(|answer| answer.contains_japanese())
+    {
+        // ē подходит и для ええ, и для えい, а ō — и для おう, и для おお
+        let spellings = [
+            typed.clone(),
+            long_e_as_ei(&typed),
+            long_o_as_oo(&typed),
+            long_o_as_oo(&long_e_as_ei(&typed)),
+        ];
+        let mut variants = Vec::new();
+        for spelling in spellings {
+            let variant = normalize(&romaji_to_hiragana(&spelling));
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
+        }
+        variants
+    } else {
+        vec![typed]
+    };
+
+    for typed in &typed_variants {
+        if let Some(answer) = alternatives
+            .iter()
+            .find(|answer| *answer == typed || equals_by_reading(answer, typed))
+        {
+            return AnswerCheck {
+                is_correct: true,
+                similarity: 1.0,
+                diff: typed_diff(answer, typed),
+            };
+        }
+    }
+    let typed = &typed_variants[0];
+
+    alternatives
+        .iter()
+        .map(|answer| {
+            let diff = char_diff(answer, typed);
+            let equal = diff
+                .iter()
+                .filter(|d| matches!(d, CharDiff::Equal(_)))
+                .count();
+            let total = answer.chars().count() + typed.chars().count();
+            let similarity = if total == 0 {
+                0.0
+            } else {
+                2.0 * equal as f64 / total as f64
+            };
+
+            AnswerCheck {
+                is_correct: false,
+                similarity,
+                diff,
+            }
+        })
+        .max_by(|a, b| a.similarity.total_cmp(&b.similarity))
+        .unwrap_or(AnswerCheck {
+            is_correct: false,
+            similarity: 0.0,
+            diff: typed.chars().map(CharDiff::Extra).collect(),
+        })
+}
+
+/// Приводит ответ к виду для сравнения: без лишних пробелов, регистра,
+/// завершающей пунктуации и с катаканой, сведенной к хирагане
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(TRAILING_PUNCTUATION)
+        .to_lowercase()
+        .to_hiragana()
+}
+
+fn equals_by_reading(answer: &str, typed: &str) -> bool {
+    if !answer.contains_japanese() || !typed.contains_japanese() {
+        return false;
+    }
+
+    answer.equals_by_reading(typed).unwrap_or(false)
+        || (answer.contains_kanji()
+            && !typed.contains_kanji()
+            && answer.as_reading().is_ok_and(|reading| reading == typed))
+}
+
+/// Для ответа, совпавшего по чтению, символы ввода считаются верными
+fn typed_diff(answer: &str, typed: &str) -> Vec<CharDiff> {
+    if answer == typed {
+        char_diff(answer, typed)
+    } else {
+        typed.chars().map(CharDiff::Equal).collect()
+    }
+}
+
+/// Посимвольный diff на основе наибольшей общей подпоследовательности
+fn char_diff(expected: &str, typed: &str) -> Vec<CharDiff> {
+    let expected: Vec<char> = expected.chars().collect();
+    let typed: Vec<char> = typed.chars().collect();
+
+    let mut lcs = vec![vec![0usize; typed.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..typed.len()).rev() {
+            lcs[i][j] = if expected[i] == typed[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::with_capacity(expected.len().max(typed.len()));
+    while i < expected.len() && j < typed.len() {
+        if expected[i] == typed[j] {
+            diff.push(CharDiff::Equal(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(CharDiff::Missing(expected[i]));
+            i += 1;
+        } else {
+            diff.push(CharDiff::Extra(typed[j]));
+            j += 1;
+        }
+    }
+    diff.extend(expected[i..].iter().copied().map(CharDiff::Missing));
+    diff.extend(typed[j..].iter().copied().map(CharDiff::Extra));
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(answers: &[&str]) -> Vec<String> {
+        answers.iter().map(|answer| answer.to_string()).collect()
+    }
+
+    #[test]
+    fn should_accept_answer_after_normalization() {
+        let check = check_answer(&expected(&["Есть, иметься"]), "  иметься. ");
+
+        assert!(check.is_correct());
+        assert_eq!(check.suggested_rating(), Rating::Good);
+    }
+
+    #[test]
+    fn should_fold_katakana_to_hiragana() {
+        let check = check_answer(&expected(&["がくせい"]), "ガクセイ");
+
+        assert!(check.is_correct());
+    }
+
+    #[test]
+    fn should_accept_romaji_for_kana_answer() {
+        let check = check_answer(&expected(&["がっこう"]), "gakkou");
+
+        assert!(check.is_correct());
+    }
+
+    #[test]
+    fn should_accept_kana_reading_for_kanji_answer() {
+        for (answer, typed) in [
+            ("学生", "がくせい"),
+            ("先生", "せんせい"),
+            ("東京", "とうきょう"),
+        ] {
+            assert!(check_answer(&expected(&[answer]), typed).is_correct());
+        }
+    }
+
+    #[test]
+    fn should_accept_long_e_as_ee_and_ei() {
+        assert!(check_answer(&expected(&["せんせい"]), "sensē").is_correct());
+        assert!(check_answer(&expected(&["おねえさん"]), "onêsan").is_correct());
+    }
+
+    #[test]
+    fn should_accept_long_o_as_ou_and_oo() {
+        assert!(check_answer(&expected(&["おおきい"]), "ōkii").is_correct());
+        assert!(check_answer(&expected(&["とおり"]), "tōri").is_correct());
+        assert!(check_answer(&expected(&["とうきょう"]), "tōkyō").is_correct());
+        assert!(check_answer(&expected(&["おおどおり"]), "ōdōri").is_correct());
+    }
+
+    #[test]
+    fn should_suggest_hard_for_typo() {
+        let check = check_answer(&expected(&["студент"]), "студнет");
+
+        assert!(!check.is_correct());
+        assert_eq!(check.suggested_rating(), Rating::Hard);
+    }
+
+    #[test]
+    fn should_suggest_again_for_wrong_answer() {
+        let check = check_answer(&expected(&["студент"]), "дом");
+
+        assert_eq!(check.suggested_rating(), Rating::Again);
+    }
+
+    #[test]
+    fn should_build_char_diff() {
+        let check = check_answer(&expected(&["cat"]), "cart");
+
+        assert_eq!(
+            check.diff(),
+            &[
+                CharDiff::Equal('c'),
+                CharDiff::Equal('a'),
+                CharDiff::Extra('r'),
+                CharDiff::Equal('t'),
+            ]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::domain::{
+    OrigaError, dictionary::vocabulary::VocabularyEntryStoredType, pitch_accent::PitchAccent,
+};
+
+/// Заполняет ударения слов в чанках словаря из таблицы ударений в формате
+/// Kanjium `accents.txt`: строки `слово\tчтение\tударения`, где ударения
+/// перечислены через запятую, а пустое чтение совпадает со словом.
+/// Слова, которых нет в таблице, сохраняют свои ударения. Возвращает чанки
+/// в том же порядке в JSON.
+pub fn import_pitch_accents(
+    accents: &str,
+    existing_chunks: &[&str],
+) -> Result<Vec<String>, OrigaError> {
+    let accents = parse_accents(accents);
+    if accents.is_empty() {
+        return Err(OrigaError::JmdictImportError {
+            reason: "No pitch accents found".to_string(),
+        });
+    }
+
+    existing_chunks
+        .iter()
+        .map(|chunk| {
+            let mut entries: BTreeMap<String, VocabularyEntryStoredType> =
+                serde_json::from_str(chunk).map_err(|e| OrigaError::JmdictImportError {
+                    reason: format!("Failed to parse existing chunk: {}", e),
+                })?;
+
+            for (word, entry) in entries.iter_mut() {
+                if let Some(word_accents) = accents.get(word) {
+                    entry.pitch_accents = word_accents.clone();
+                }
+            }
+
+            serde_json::to_string_pretty(&entries).map_err(|e| OrigaError::JmdictImportError {
+                reason: format!("Failed to serialize chunk: {}", e),
+            })
+        })
+        .collect()
+}
+
+/// Ударения по словам. Номера с пометами вроде `(名)0` читаются по цифрам,
+/// номера за пределами чтения пропускаются
+fn parse_accents(accents: &str) -> HashMap<String, Vec<PitchAccent>> {
+    let mut parsed: HashMap<String, Vec<PitchAccent>> = HashMap::new();
+
+    for line in accents.lines() {
+        let mut columns = line.split('\t');
+        let (Some(word), Some(reading), Some(downsteps)) =
+            (columns.next(), columns.next(), columns.next())
+        else {
+            continue;
+        };
+        let word = word.trim();
+        let reading = match reading.trim() {
+            "" => word,
+            reading => reading,
+        };
+
+        let word_accents = parsed.entry(word.to_string()).or_default();
+        for downstep in downsteps.split(',') {
+            let digits: String = downstep.chars().filter(char::is_ascii_digit).collect();
+            let Some(accent) = digits
+                .parse()
+                .ok()
+                .and_then(|downstep| PitchAccent::new(reading.to_string(), downstep).ok())
+            else {
+                continue;
+            };
+            if !word_accents.contains(&accent) {
+                word_accents.push(accent);
+            }
+        }
+    }
+
+    parsed.retain(|_, word_accents| !word_accents.is_empty());
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCENTS: &str = "箸\tはし\t1\n橋\tはし\t2\n今日\tきょう\t1\n今日\tこんにち\t(名)2,0\nさくら\t\t0\n端\tはし\t9\n";
+
+    #[test]
+    fn should_fill_pitch_accents_of_known_words() {
+        let existing = r#"{"今日": {"level": "N5"}, "端": {"level": "N3"}, "さくら": {}}"#;
+
+        let chunks = import_pitch_accents(ACCENTS, &[existing]).unwrap();
+        let vocabulary: BTreeMap<String, VocabularyEntryStoredType> =
+            serde_json::from_str(&chunks[0]).unwrap();
+
+        let accents: Vec<_> = vocabulary["今日"]
+            .pitch_accents
+            .iter()
+            .map(|accent| (accent.reading(), accent.downstep()))
+            .collect();
+        assert_eq!(
+            accents,
+            vec![("きょう", 1), ("こんにち", 2), ("こんにち", 0)]
+        );
+        assert_eq!(vocabulary["さくら"].pitch_accents[0].reading(), "さくら");
+        assert!(vocabulary["端"].pitch_accents.is_empty());
+        assert_eq!(vocabulary.len(), 3);
+    }
+}
@@ -0,0 +1,466 @@
+use std::collections::BTreeMap;
+
+use crate::domain::{
+    OrigaError,
+    dictionary::vocabulary::{VocabularyEntryStoredType, VocabularySense, is_common_tag},
+    value_objects::NativeLanguage,
+};
+
+const TRANSLATION_GLOSSES: usize = 3;
+
+/// Заголовок статьи JMdict в том виде, в котором он попадает в словарь
+struct JmdictEntry {
+    word: String,
+    readings: Vec<String>,
+    senses: Vec<VocabularySense>,
+    frequency_tags: Vec<String>,
+}
+
+impl JmdictEntry {
+    fn is_common(&self) -> bool {
+        self.frequency_tags.iter().any(|tag| is_common_tag(tag))
+    }
+}
+
+/// Чтение статьи JMdict и написания кандзи, к которым оно относится
+struct JmdictReading {
+    text: String,
+    frequency_tags: Vec<String>,
+    restrictions: Vec<String>,
+    no_kanji: bool,
+}
+
+impl JmdictReading {
+    fn applies_to(&self, kanji: &str) -> bool {
+        !self.no_kanji
+            && (self.restrictions.is_empty() || self.restrictions.iter().any(|r| r == kanji))
+    }
+}
+
+/// Собирает встроенный словарь из XML JMdict. Берутся слова, которые уже есть
+/// в чанках, и частотные слова JMdict; каждое написание кандзи становится
+/// отдельным словом. Если одно написание встречается в нескольких статьях,
+/// остается первая частотная из них, статьи не смешиваются. Уровень, примеры
+/// и уже заполненные переводы берутся из существующих чанков, а чтения,
+/// значения и метки частотности — из JMdict. Возвращает `chunks_count` чанков в JSON.
+pub fn import_jmdict(
+    xml: &str,
+    existing_chunks: &[&str],
+    chunks_count: usize,
+) -> Result<Vec<String>, OrigaError> {
+    if chunks_count == 0 {
+        return Err(OrigaError::JmdictImportError {
+            reason: "Chunks count must be greater than zero".to_string(),
+        });
+    }
+
+    let mut vocabulary = BTreeMap::new();
+    for chunk in existing_chunks {
+        let entries: BTreeMap<String, VocabularyEntryStoredType> = serde_json::from_str(chunk)
+            .map_err(|e| OrigaError::JmdictImportError {
+                reason: format!("Failed to parse existing chunk: {}", e),
+            })?;
+        vocabulary.extend(entries);
+    }
+
+    let mut selected: BTreeMap<String, JmdictEntry> = BTreeMap::new();
+    for entry in parse_jmdict(xml)? {
+        if !entry.is_common() && !vocabulary.contains_key(&entry.word) {
+            continue;
+        }
+        match selected.get(&entry.word) {
+            Some(chosen) if chosen.is_common() || !entry.is_common() => {}
+            _ => {
+                selected.insert(entry.word.clone(), entry);
+            }
+        }
+    }
+
+    for (word, entry) in selected {
+        let stored: &mut VocabularyEntryStoredType = vocabulary.entry(word).or_default();
+        stored.readings = entry.readings;
+        stored.senses = entry.senses;
+        stored.frequency_tags = entry.frequency_tags;
+
+        if stored.russian_translation.is_empty() {
+            stored.russian_translation = summary(&stored.senses, &NativeLanguage::Russian);
+        }
+        if stored.english_translation.is_empty() {
+            stored.english_translation = summary(&stored.senses, &NativeLanguage::English);
+        }
+    }
+
+    let chunk_size = vocabulary.len().div_ceil(chunks_count).max(1);
+    let mut chunks = vec![BTreeMap::new(); chunks_count];
+    for (i, (word, entry)) in vocabulary.into_iter().enumerate() {
+        chunks[i / chunk_size].insert(word, entry);
+    }
+
+    chunks
+        .iter()
+        .map(|chunk| {
+            serde_json::to_string_pretty(chunk).map_err(|e| OrigaError::JmdictImportError {
+                reason: format!("Failed to serialize chunk: {}", e),
+            })
+        })
+        .collect()
+}
+
+/// Первые переводы первого значения, в котором они есть
+fn summary(senses: &[VocabularySense], native_language: &NativeLanguage) -> String {
+    senses
+        .iter()
+        .map(|sense| sense.glosses(native_language))
+        .find(|glosses| !glosses.is_empty())
+        .map(|glosses| {
+            glosses
+                .iter()
+                .take(TRANSLATION_GLOSSES)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+        .unwrap_or_default()
+}
+
+fn parse_jmdict(xml: &str) -> Result<Vec<JmdictEntry>, OrigaError> {
+    let entries: Vec<_> = elements(xml, "entry")
+        .map(|(_, entry)| parse_entry(entry))
+        .collect();
+
+    if entries.is_empty() {
+        return Err(OrigaError::JmdictImportError {
+            reason: "No entries found in JMdict".to_string(),
+        });
+    }
+
+    Ok(entries.into_iter().flatten().collect())
+}
+
+/// Каждое написание кандзи становится заголовком со своими чтениями, значениями
+/// и метками частотности, а статья без кандзи — заголовком из первого чтения.
+/// Часть речи в JMdict действует и на следующие значения, пока не указана новая.
+fn parse_entry(entry: &str) -> Vec<JmdictEntry> {
+    let kanji: Vec<(String, Vec<String>)> = elements(entry, "k_ele")
+        .filter_map(|(_, k_ele)| {
+            let keb = elements(k_ele, "keb").map(|(_, keb)| decode(keb)).next()?;
+            Some((keb, texts(k_ele, "ke_pri")))
+        })
+        .collect();
+
+    let readings: Vec<JmdictReading> = elements(entry, "r_ele")
+        .filter_map(|(_, r_ele)| {
+            Some(JmdictReading {
+                text: elements(r_ele, "reb").map(|(_, reb)| decode(reb)).next()?,
+                frequency_tags: texts(r_ele, "re_pri"),
+                restrictions: texts(r_ele, "re_restr"),
+                no_kanji: r_ele.contains("<re_nokanji"),
+            })
+        })
+        .collect();
+
+    let mut senses = Vec::new();
+    let mut last_part_of_speech = Vec::new();
+    for (_, sense_xml) in elements(entry, "sense") {
+        let mut sense = VocabularySense::default();
+
+        let part_of_speech = texts(sense_xml, "pos");
+        if !part_of_speech.is_empty() {
+            last_part_of_speech = part_of_speech;
+        }
+        for pos in &last_part_of_speech {
+            sense.push_part_of_speech(pos.clone());
+        }
+
+        for (_, note) in elements(sense_xml, "misc").chain(elements(sense_xml, "s_inf")) {
+            sense.push_note(decode(note));
+        }
+
+        for (attributes, gloss) in elements(sense_xml, "gloss") {
+            let native_language = match gloss_language(attributes) {
+                "eng" => NativeLanguage::English,
+                "rus" => NativeLanguage::Russian,
+                _ => continue,
+            };
+            sense.push_gloss(&native_language, decode(gloss));
+        }
+
+        senses.push((texts(sense_xml, "stagk"), sense));
+    }
+
+    if kanji.is_empty() {
+        let Some(word) = readings.first().map(|reading| reading.text.clone()) else {
+            return Vec::new();
+        };
+        return vec![JmdictEntry {
+            word,
+            readings: readings.iter().map(|r| r.text.clone()).collect(),
+            senses: senses.into_iter().map(|(_, sense)| sense).collect(),
+            frequency_tags: unique(readings.into_iter().flat_map(|r| r.frequency_tags)),
+        }];
+    }
+
+    kanji
+        .into_iter()
+        .map(|(word, frequency_tags)| JmdictEntry {
+            readings: readings
+                .iter()
+                .filter(|reading| reading.applies_to(&word))
+                .map(|reading| reading.text.clone())
+                .collect(),
+            senses: senses
+                .iter()
+                .filter(|(kanji, _)| kanji.is_empty() || kanji.contains(&word))
+                .map(|(_, sense)| sense.clone())
+                .collect(),
+            frequency_tags: unique(frequency_tags),
+            word,
+        })
+        .collect()
+}
+
+/// Раскрытый текст всех элементов `tag`
+fn texts(xml: &str, tag: &str) -> Vec<String> {
+    elements(xml, tag).map(|(_, text)| decode(text)).collect()
+}
+
+fn unique(values: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut unique = Vec::new();
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    unique
+}
+
+fn gloss_language(attributes: &str) -> &str {
+    attributes
+        .split_once("xml:lang=\"")
+        .and_then(|(_, rest)| rest.split('"').next())
+        .unwrap_or("eng")
+}
+
+/// Содержимое элементов `tag` без вложенных элементов с тем же именем:
+/// пары из атрибутов и текста внутри тега
+pub(super) fn elements<'a>(xml: &'a str, tag: &str) -> impl Iterator<Item = (&'a str, &'a str)> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut rest = xml;
+
+    std::iter::from_fn(move || {
+        loop {
+            let start = rest.find(&open)?;
+            let after_name = &rest[start + open.len()..];
+            let tag_end = after_name.find('>')?;
+            let attributes = &after_name[..tag_end];
+
+            if !attributes.is_empty() && !attributes.starts_with(char::is_whitespace) {
+                rest = after_name;
+                continue;
+            }
+            if attributes.ends_with('/') {
+                rest = &after_name[tag_end + 1..];
+                continue;
+            }
+
+            let content = &after_name[tag_end + 1..];
+            let content_end = content.find(&close)?;
+            rest = &content[content_end + close.len()..];
+            return Some((attributes, &content[..content_end]));
+        }
+    })
+}
+
+/// Раскрывает стандартные сущности XML, а сущности JMdict вида `&v5k;`
+/// заменяет их кодом
+pub(super) fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text.trim();
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find(';') {
+            Some(end) => {
+                match &after[..end] {
+                    "amp" => decoded.push('&'),
+                    "lt" => decoded.push('<'),
+                    "gt" => decoded.push('>'),
+                    "quot" => decoded.push('"'),
+                    "apos" => decoded.push('\''),
+                    entity => decoded.push_str(entity),
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = after;
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JMDICT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<JMdict>
+<entry>
+<ent_seq>1000001</ent_seq>
+<k_ele>
+<keb>食べる</keb>
+<ke_pri>ichi1</ke_pri>
+</k_ele>
+<r_ele>
+<reb>たべる</reb>
+<re_pri>ichi1</re_pri>
+</r_ele>
+<sense>
+<pos>&v1;</pos>
+<pos>&vt;</pos>
+<gloss>to eat</gloss>
+<gloss xml:lang="rus">есть</gloss>
+</sense>
+<sense>
+<misc>&col;</misc>
+<s_inf>figurative &amp; slang</s_inf>
+<gloss>to live on</gloss>
+<gloss xml:lang="ger">leben</gloss>
+</sense>
+</entry>
+<entry>
+<ent_seq>1000002</ent_seq>
+<r_ele>
+<reb>ああ</reb>
+</r_ele>
+<sense>
+<pos>&int;</pos>
+<gloss>ah!</gloss>
+</sense>
+</entry>
+<entry>
+<ent_seq>1000003</ent_seq>
+<k_ele>
+<keb>頂く</keb>
+<ke_pri>ichi1</ke_pri>
+</k_ele>
+<k_ele>
+<keb>戴く</keb>
+</k_ele>
+<r_ele>
+<reb>いただく</reb>
+<re_pri>ichi1</re_pri>
+</r_ele>
+<sense>
+<pos>&v5k;</pos>
+<gloss>to receive</gloss>
+</sense>
+<sense>
+<stagk>頂く</stagk>
+<gloss>to eat</gloss>
+</sense>
+</entry>
+<entry>
+<ent_seq>1000004</ent_seq>
+<k_ele>
+<keb>家</keb>
+<ke_pri>ichi1</ke_pri>
+</k_ele>
+<r_ele>
+<reb>いえ</reb>
+</r_ele>
+<sense>
+<pos>&n;</pos>
+<gloss>house</gloss>
+</sense>
+</entry>
+<entry>
+<ent_seq>1000005</ent_seq>
+<k_ele>
+<keb>家</keb>
+</k_ele>
+<r_ele>
+<reb>け</reb>
+</r_ele>
+<sense>
+<pos>&suf;</pos>
+<gloss>family</gloss>
+</sense>
+</entry>
+</JMdict>"#;
+
+    #[test]
+    fn should_parse_senses_with_inherited_part_of_speech() {
+        let entries = parse_jmdict(JMDICT).unwrap();
+
+        let entry = &entries[0];
+        assert_eq!(entry.word, "食べる");
+        assert_eq!(entry.readings, vec!["たべる"]);
+        assert_eq!(entry.frequency_tags, vec!["ichi1"]);
+        assert_eq!(entry.senses.len(), 2);
+        assert_eq!(entry.senses[1].part_of_speech(), &["v1", "vt"]);
+        assert_eq!(entry.senses[1].notes(), &["col", "figurative & slang"]);
+        assert_eq!(entry.senses[0].glosses(&NativeLanguage::Russian), &["есть"]);
+        assert!(entry.senses[1].glosses(&NativeLanguage::Russian).is_empty());
+    }
+
+    #[test]
+    fn should_use_reading_as_word_without_kanji() {
+        let entries = parse_jmdict(JMDICT).unwrap();
+
+        assert_eq!(entries[1].word, "ああ");
+    }
+
+    #[test]
+    fn should_make_every_kanji_spelling_a_word() {
+        let entries = parse_jmdict(JMDICT).unwrap();
+
+        let itadaku = entries.iter().find(|e| e.word == "頂く").unwrap();
+        let itadaku_rare = entries.iter().find(|e| e.word == "戴く").unwrap();
+        assert_eq!(itadaku.senses.len(), 2);
+        assert_eq!(itadaku_rare.readings, vec!["いただく"]);
+        assert_eq!(itadaku_rare.senses.len(), 1);
+        assert!(!itadaku_rare.is_common());
+    }
+
+    #[test]
+    fn should_keep_existing_translations_examples_and_level() {
+        let existing = r#"{"食べる": {"level": "N5", "russian_translation": "кушать",
+            "english_translation": "", "english_examples": [],
+            "russian_examples": [{"text": "ご飯を食べる", "translation": "есть рис"}]}}"#;
+
+        let chunks = import_jmdict(JMDICT, &[existing], 1).unwrap();
+        let vocabulary: BTreeMap<String, VocabularyEntryStoredType> =
+            serde_json::from_str(&chunks[0]).unwrap();
+
+        let entry = &vocabulary["食べる"];
+        assert_eq!(entry.level, "N5");
+        assert_eq!(entry.russian_translation, "кушать");
+        assert_eq!(entry.english_translation, "to eat");
+        assert_eq!(
+            serde_json::to_value(&entry.russian_examples).unwrap(),
+            serde_json::json!([{"text": "ご飯を食べる", "translation": "есть рис"}])
+        );
+        assert_eq!(entry.senses.len(), 2);
+    }
+
+    #[test]
+    fn should_import_only_known_or_common_words_without_merging_homographs() {
+        let existing = r#"{"ああ": {"level": "N4"}}"#;
+
+        let chunks = import_jmdict(JMDICT, &[existing], 1).unwrap();
+        let vocabulary: BTreeMap<String, VocabularyEntryStoredType> =
+            serde_json::from_str(&chunks[0]).unwrap();
+
+        let words: Vec<_> = vocabulary.keys().map(String::as_str).collect();
+        assert_eq!(words, vec!["ああ", "家", "頂く", "食べる"]);
+        assert_eq!(vocabulary["家"].readings, vec!["いえ"]);
+        assert_eq!(vocabulary["家"].senses.len(), 1);
+        assert_eq!(vocabulary["ああ"].english_translation, "ah!");
+    }
+}
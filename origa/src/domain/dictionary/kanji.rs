@@ -6,12 +6,14 @@ use crate::domain::{
     OrigaError,
     dictionary::{
         radical::{RADICAL_DICTIONARY, RadicalInfo},
+        stroke_order::STROKE_ORDER_DICTIONARY,
         vocabulary::VOCABULARY_DICTIONARY,
     },
     value_objects::{JapaneseLevel, NativeLanguage},
 };
 
 const KANJI_DATA: &str = include_str!("./kanji.json");
+const KANJIDIC_DATA: &str = include_str!("./kanjidic2.json");
 pub static KANJI_DICTIONARY: LazyLock<KanjiDatabase> = LazyLock::new(KanjiDatabase::new);
 
 pub struct KanjiDatabase {
@@ -46,6 +48,14 @@ pub struct KanjiInfo {
     description: String,
     radicals: Vec<char>,
     popular_words: Vec<String>,
+    #[serde(default)]
+    onyomi: Vec<String>,
+    #[serde(default)]
+    kunyomi: Vec<String>,
+    #[serde(default)]
+    nanori: Vec<String>,
+    #[serde(default)]
+    stroke_count: u8,
 }
 
 impl KanjiInfo {
@@ -72,6 +82,25 @@ impl KanjiInfo {
             .collect()
     }
 
+    /// Китайские чтения, записанные катаканой
+    pub fn onyomi(&self) -> &[String] {
+        &self.onyomi
+    }
+
+    /// Японские чтения, окуригана отделена точкой: `た.べる`
+    pub fn kunyomi(&self) -> &[String] {
+        &self.kunyomi
+    }
+
+    /// Чтения, которые встречаются только в именах
+    pub fn nanori(&self) -> &[String] {
+        &self.nanori
+    }
+
+    pub fn stroke_count(&self) -> u8 {
+        self.stroke_count
+    }
+
     pub fn popular_words(&self) -> &[String] {
         &self.popular_words
     }
@@ -100,7 +129,17 @@ impl Default for KanjiDatabase {
 
 impl KanjiDatabase {
     pub fn new() -> Self {
-        let kanji_db: KanjiDatabaseStoredType = serde_json::from_str(KANJI_DATA).unwrap();
+        Self::from_data(KANJI_DATA, KANJIDIC_DATA)
+    }
+
+    pub(super) fn from_data(kanji_data: &str, kanjidic_data: &str) -> Self {
+        let kanji_db: KanjiDatabaseStoredType = serde_json::from_str(kanji_data).unwrap();
+        let kanjidic: KanjidicStoredType = serde_json::from_str(kanjidic_data).unwrap();
+        let mut kanjidic = kanjidic
+            .characters
+            .into_iter()
+            .map(|character| (character.literal.clone(), character))
+            .collect::<HashMap<_, _>>();
 
         let kanji_map = kanji_db
             .kanji
@@ -114,6 +153,33 @@ impl KanjiDatabase {
                     .flat_map(|r| r.chars().collect::<Vec<_>>())
                     .collect::<Vec<char>>();
 
+                let character = kanjidic.remove(&k.kanji).unwrap_or_default();
+                let readings = character
+                    .reading_meaning
+                    .groups
+                    .iter()
+                    .flat_map(|group| group.readings.iter())
+                    .collect::<Vec<_>>();
+                let readings_of = |reading_type: &str| {
+                    readings
+                        .iter()
+                        .filter(|reading| reading.reading_type == reading_type)
+                        .map(|reading| reading.value.clone())
+                        .collect::<Vec<_>>()
+                };
+                let stroke_count = character
+                    .misc
+                    .stroke_counts
+                    .first()
+                    .copied()
+                    .or_else(|| {
+                        STROKE_ORDER_DICTIONARY
+                            .get_stroke_order(&k.kanji)
+                            .ok()
+                            .map(|stroke_order| stroke_order.stroke_count() as u8)
+                    })
+                    .unwrap_or_default();
+
                 (
                     kanji_char.to_string(),
                     KanjiInfo {
@@ -123,6 +189,10 @@ impl KanjiDatabase {
                         description: k.description,
                         radicals,
                         popular_words: k.popular_words,
+                        onyomi: readings_of("ja_on"),
+                        kunyomi: readings_of("ja_kun"),
+                        nanori: character.reading_meaning.nanori,
+                        stroke_count,
                     },
                 )
             })
@@ -160,6 +230,57 @@ struct KanjiDatabaseStoredType {
     kanji: Vec<KanjiStoredType>,
 }
 
+/// Запись KANJIDIC2 в формате jmdict-simplified
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct KanjidicCharacterStoredType {
+    pub(super) literal: String,
+    #[serde(default)]
+    pub(super) misc: KanjidicMiscStoredType,
+    #[serde(default)]
+    pub(super) reading_meaning: KanjidicReadingMeaningStoredType,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct KanjidicMiscStoredType {
+    #[serde(default)]
+    pub(super) stroke_counts: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub(super) struct KanjidicReadingMeaningStoredType {
+    #[serde(default)]
+    pub(super) groups: Vec<KanjidicGroupStoredType>,
+    #[serde(default)]
+    pub(super) nanori: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct KanjidicGroupStoredType {
+    #[serde(default)]
+    pub(super) readings: Vec<KanjidicReadingStoredType>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct KanjidicReadingStoredType {
+    #[serde(rename = "type")]
+    pub(super) reading_type: String,
+    pub(super) value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct KanjidicStoredType {
+    pub(super) characters: Vec<KanjidicCharacterStoredType>,
+}
+
+/// Кандзи встроенного словаря
+pub(super) fn bundled_kanji() -> Vec<String> {
+    serde_json::from_str::<KanjiDatabaseStoredType>(KANJI_DATA)
+        .map(|kanji_db| kanji_db.kanji.into_iter().map(|k| k.kanji).collect())
+        .unwrap_or_default()
+}
+
 pub(crate) fn parse_jlpt_level(s: &str) -> JapaneseLevel {
     match s {
         "N5" => JapaneseLevel::N5,
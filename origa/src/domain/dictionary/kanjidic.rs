@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use crate::domain::{
+    OrigaError,
+    dictionary::{
+        jmdict::{decode, elements},
+        kanji::{
+            KanjidicCharacterStoredType, KanjidicGroupStoredType, KanjidicMiscStoredType,
+            KanjidicReadingMeaningStoredType, KanjidicReadingStoredType, KanjidicStoredType,
+            bundled_kanji,
+        },
+    },
+};
+
+const READING_TYPES: [&str; 2] = ["ja_on", "ja_kun"];
+
+/// Собирает kanjidic2.json из XML KANJIDIC2 в формате jmdict-simplified:
+/// чтения онъёми и кунъёми, нанори и число черт для кандзи встроенного словаря
+pub fn import_kanjidic2(xml: &str) -> Result<String, OrigaError> {
+    let kanji: HashSet<String> = bundled_kanji().into_iter().collect();
+    let characters: Vec<_> = elements(xml, "character")
+        .filter_map(|(_, character)| parse_character(character))
+        .filter(|character| kanji.contains(&character.literal))
+        .collect();
+
+    if characters.is_empty() {
+        return Err(OrigaError::JmdictImportError {
+            reason: "No known kanji found in KANJIDIC2".to_string(),
+        });
+    }
+
+    serde_json::to_string_pretty(&KanjidicStoredType { characters }).map_err(|e| {
+        OrigaError::JmdictImportError {
+            reason: format!("Failed to serialize KANJIDIC2: {}", e),
+        }
+    })
+}
+
+fn parse_character(character: &str) -> Option<KanjidicCharacterStoredType> {
+    let literal = elements(character, "literal")
+        .map(|(_, literal)| decode(literal))
+        .next()?;
+
+    let stroke_counts = elements(character, "stroke_count")
+        .filter_map(|(_, count)| decode(count).parse().ok())
+        .collect();
+
+    let groups = elements(character, "rmgroup")
+        .map(|(_, group)| KanjidicGroupStoredType {
+            readings: elements(group, "reading")
+                .filter_map(|(attributes, value)| {
+                    let reading_type = attribute(attributes, "r_type")?;
+                    READING_TYPES
+                        .contains(&reading_type)
+                        .then(|| KanjidicReadingStoredType {
+                            reading_type: reading_type.to_string(),
+                            value: decode(value),
+                        })
+                })
+                .collect(),
+        })
+        .collect();
+
+    let nanori = elements(character, "nanori")
+        .map(|(_, nanori)| decode(nanori))
+        .collect();
+
+    Some(KanjidicCharacterStoredType {
+        literal,
+        misc: KanjidicMiscStoredType { stroke_counts },
+        reading_meaning: KanjidicReadingMeaningStoredType { groups, nanori },
+    })
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    attributes
+        .split_once(&format!("{}=\"", name))
+        .and_then(|(_, rest)| rest.split('"').next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dictionary::kanji::KanjiDatabase;
+
+    const KANJIDIC2: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kanjidic2>
+<character>
+<literal>食</literal>
+<misc>
+<grade>2</grade>
+<stroke_count>9</stroke_count>
+</misc>
+<reading_meaning>
+<rmgroup>
+<reading r_type="pinyin">shi2</reading>
+<reading r_type="ja_on">ショク</reading>
+<reading r_type="ja_on">ジキ</reading>
+<reading r_type="ja_kun">く.う</reading>
+<reading r_type="ja_kun">た.べる</reading>
+<meaning>eat</meaning>
+</rmgroup>
+<nanori>あき</nanori>
+</reading_meaning>
+</character>
+<character>
+<literal>丂</literal>
+<misc>
+<stroke_count>2</stroke_count>
+</misc>
+</character>
+</kanjidic2>"#;
+
+    #[test]
+    fn should_load_imported_readings_of_known_kanji() {
+        let kanjidic = import_kanjidic2(KANJIDIC2).unwrap();
+        assert!(!kanjidic.contains("丂"));
+
+        let database = KanjiDatabase::from_data(include_str!("./kanji.json"), &kanjidic);
+        let info = database.get_kanji_info("食").unwrap();
+
+        assert_eq!(info.onyomi(), ["ショク", "ジキ"]);
+        assert!(info.kunyomi().contains(&"た.べる".to_string()));
+        assert_eq!(info.nanori(), ["あき"]);
+        assert_eq!(info.stroke_count(), 9);
+    }
+}
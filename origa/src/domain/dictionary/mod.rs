@@ -1,7 +1,17 @@
+mod accents;
+mod jmdict;
 mod kanji;
+mod kanjidic;
 mod radical;
+mod stroke_order;
 mod vocabulary;
 
+pub use accents::import_pitch_accents;
+pub use jmdict::import_jmdict;
 pub use kanji::{KANJI_DICTIONARY, KanjiInfo, PopularWord};
+pub use kanjidic::import_kanjidic2;
 pub use radical::{RADICAL_DICTIONARY, RadicalInfo};
-pub use vocabulary::{VOCABULARY_DICTIONARY, VocabularyInfo};
+pub use stroke_order::{
+    KanjiStrokeOrder, STROKE_ORDER_DICTIONARY, Stroke, StrokeOrderDatabase, StrokePoint,
+};
+pub use vocabulary::{VOCABULARY_DICTIONARY, VocabularyInfo, VocabularySense};
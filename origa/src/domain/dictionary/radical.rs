@@ -1,4 +1,7 @@
-use std::{collections::HashMap, sync::LazyLock};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::LazyLock,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -103,6 +106,26 @@ impl RadicalDatabase {
     pub fn known_radicals(&self) -> &[char] {
         &self.known_radicals
     }
+
+    /// Кандзи, в которых есть все переданные радикалы
+    pub fn kanji_with_radicals(&self, radicals: &[char]) -> Result<HashSet<char>, OrigaError> {
+        let (first, rest) = radicals.split_first().ok_or(OrigaError::InvalidValues {
+            reason: "At least one radical is required".to_string(),
+        })?;
+
+        let mut kanji: HashSet<char> = self
+            .get_radical_info(first)?
+            .kanji()
+            .iter()
+            .copied()
+            .collect();
+        for radical in rest {
+            let radical_kanji = self.get_radical_info(radical)?.kanji();
+            kanji.retain(|k| radical_kanji.contains(k));
+        }
+
+        Ok(kanji)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -119,3 +142,18 @@ struct RadicalStoredType {
 struct RadkfilStoredType {
     radicals: HashMap<String, RadicalStoredType>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_kanji_containing_all_radicals() {
+        let kanji = RADICAL_DICTIONARY
+            .kanji_with_radicals(&['木', '日'])
+            .unwrap();
+
+        assert!(kanji.contains(&'東'));
+        assert!(!kanji.contains(&'林'));
+    }
+}
@@ -0,0 +1,315 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use crate::domain::OrigaError;
+
+const KANJI_ANIMATIONS: &[(char, &str)] =
+    include!(concat!(env!("OUT_DIR"), "/kanji_animations.rs"));
+const BEZIER_SAMPLES: usize = 8;
+
+pub static STROKE_ORDER_DICTIONARY: LazyLock<StrokeOrderDatabase> =
+    LazyLock::new(StrokeOrderDatabase::new);
+
+pub struct StrokeOrderDatabase {
+    animations: HashMap<char, &'static str>,
+}
+
+/// Точка в системе координат SVG кандзи 109x109
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokePoint {
+    x: f64,
+    y: f64,
+}
+
+impl StrokePoint {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+/// Одна черта кандзи: исходный путь SVG и точки ломаной, повторяющей его
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    number: usize,
+    path: String,
+    points: Vec<StrokePoint>,
+}
+
+impl Stroke {
+    /// Номер черты в порядке написания, начиная с 1
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn points(&self) -> &[StrokePoint] {
+        &self.points
+    }
+
+    pub fn start(&self) -> StrokePoint {
+        self.points[0]
+    }
+
+    pub fn end(&self) -> StrokePoint {
+        self.points[self.points.len() - 1]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanjiStrokeOrder {
+    kanji: char,
+    strokes: Vec<Stroke>,
+    animation_svg: &'static str,
+}
+
+impl KanjiStrokeOrder {
+    pub fn kanji(&self) -> char {
+        self.kanji
+    }
+
+    /// Черты в порядке написания
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+
+    pub fn stroke_count(&self) -> usize {
+        self.strokes.len()
+    }
+
+    /// SVG с пронумерованными чертами для показа порядка написания
+    pub fn animation_svg(&self) -> &'static str {
+        self.animation_svg
+    }
+}
+
+impl Default for StrokeOrderDatabase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StrokeOrderDatabase {
+    pub fn new() -> Self {
+        Self {
+            animations: KANJI_ANIMATIONS.iter().copied().collect(),
+        }
+    }
+
+    /// Порядок черт всех кандзи из базы
+    pub fn stroke_orders(&self) -> impl Iterator<Item = KanjiStrokeOrder> + '_ {
+        self.animations.iter().filter_map(|(kanji, animation_svg)| {
+            Some(KanjiStrokeOrder {
+                kanji: *kanji,
+                strokes: parse_strokes(animation_svg).ok()?,
+                animation_svg,
+            })
+        })
+    }
+
+    pub fn get_stroke_order(&self, kanji: &str) -> Result<KanjiStrokeOrder, OrigaError> {
+        let (kanji, animation_svg) = kanji
+            .chars()
+            .next()
+            .and_then(|c| self.animations.get(&c).map(|svg| (c, *svg)))
+            .ok_or(OrigaError::StrokeOrderError {
+                reason: format!("Kanji {} not found in stroke order database", kanji),
+            })?;
+
+        Ok(KanjiStrokeOrder {
+            kanji,
+            strokes: parse_strokes(animation_svg)?,
+            animation_svg,
+        })
+    }
+}
+
+/// Черты — это пути SVG без класса `bg`, идущие в порядке написания
+fn parse_strokes(svg: &str) -> Result<Vec<Stroke>, OrigaError> {
+    svg.split("<path")
+        .skip(1)
+        .filter_map(|tag| tag.split('>').next())
+        .filter(|tag| !tag.contains("class=\"bg\""))
+        .filter_map(|tag| {
+            let (_, rest) = tag.split_once(" d=\"")?;
+            rest.split('"').next()
+        })
+        .enumerate()
+        .map(|(i, path)| {
+            Ok(Stroke {
+                number: i + 1,
+                path: path.to_string(),
+                points: parse_path(path)?,
+            })
+        })
+        .collect()
+}
+
+/// Переводит путь SVG из команд M, C и S в ломаную
+fn parse_path(path: &str) -> Result<Vec<StrokePoint>, OrigaError> {
+    let error = || OrigaError::StrokeOrderError {
+        reason: format!("Unsupported stroke path {}", path),
+    };
+
+    let mut points = Vec::new();
+    let mut current = StrokePoint::new(0.0, 0.0);
+    let mut last_control: Option<StrokePoint> = None;
+
+    for (command, args) in path_commands(path) {
+        let relative = command.is_ascii_lowercase();
+        let offset = |x: f64, y: f64, current: StrokePoint| {
+            if relative {
+                StrokePoint::new(current.x + x, current.y + y)
+            } else {
+                StrokePoint::new(x, y)
+            }
+        };
+
+        match command.to_ascii_uppercase() {
+            'M' => {
+                for pair in args.chunks_exact(2) {
+                    current = offset(pair[0], pair[1], current);
+                    points.push(current);
+                }
+                last_control = None;
+            }
+            'C' => {
+                for segment in args.chunks_exact(6) {
+                    let first = offset(segment[0], segment[1], current);
+                    let second = offset(segment[2], segment[3], current);
+                    let end = offset(segment[4], segment[5], current);
+                    sample_bezier(&mut points, current, first, second, end);
+                    last_control = Some(second);
+                    current = end;
+                }
+            }
+            'S' => {
+                for segment in args.chunks_exact(4) {
+                    let first = last_control
+                        .map(|control| {
+                            StrokePoint::new(
+                                2.0 * current.x - control.x,
+                                2.0 * current.y - control.y,
+                            )
+                        })
+                        .unwrap_or(current);
+                    let second = offset(segment[0], segment[1], current);
+                    let end = offset(segment[2], segment[3], current);
+                    sample_bezier(&mut points, current, first, second, end);
+                    last_control = Some(second);
+                    current = end;
+                }
+            }
+            _ => return Err(error()),
+        }
+    }
+
+    if points.is_empty() {
+        return Err(error());
+    }
+
+    Ok(points)
+}
+
+fn sample_bezier(
+    points: &mut Vec<StrokePoint>,
+    start: StrokePoint,
+    first: StrokePoint,
+    second: StrokePoint,
+    end: StrokePoint,
+) {
+    for i in 1..=BEZIER_SAMPLES {
+        let t = i as f64 / BEZIER_SAMPLES as f64;
+        let u = 1.0 - t;
+        let point = |a: f64, b: f64, c: f64, d: f64| {
+            u * u * u * a + 3.0 * u * u * t * b + 3.0 * u * t * t * c + t * t * t * d
+        };
+        points.push(StrokePoint::new(
+            point(start.x, first.x, second.x, end.x),
+            point(start.y, first.y, second.y, end.y),
+        ));
+    }
+}
+
+/// Разбивает путь на команды с числовыми аргументами. Числа могут идти
+/// без разделителей: `-5.12,68.58-5.24` или `0.5.25`
+fn path_commands(path: &str) -> Vec<(char, Vec<f64>)> {
+    let mut commands: Vec<(char, Vec<f64>)> = Vec::new();
+    let mut number = String::new();
+
+    let flush = |number: &mut String, commands: &mut Vec<(char, Vec<f64>)>| {
+        if let (Ok(value), Some((_, args))) = (number.parse::<f64>(), commands.last_mut()) {
+            args.push(value);
+        }
+        number.clear();
+    };
+
+    for c in path.chars() {
+        match c {
+            c if c.is_ascii_alphabetic() => {
+                flush(&mut number, &mut commands);
+                commands.push((c, Vec::new()));
+            }
+            '-' => {
+                flush(&mut number, &mut commands);
+                number.push(c);
+            }
+            '.' if number.contains('.') => {
+                flush(&mut number, &mut commands);
+                number.push(c);
+            }
+            c if c.is_ascii_digit() || c == '.' => number.push(c),
+            _ => flush(&mut number, &mut commands),
+        }
+    }
+    flush(&mut number, &mut commands);
+
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_numbers_without_separators() {
+        let commands = path_commands("M11,54.25c3.19-0.62,.5.75");
+
+        assert_eq!(
+            commands,
+            vec![
+                ('M', vec![11.0, 54.25]),
+                ('c', vec![3.19, -0.62, 0.5, 0.75])
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_strokes_in_order() {
+        let svg = r#"<svg><g><path class="bg" d="M54.5,0L54.5,109"/></g><g><path d="M10,10c0,0,10,0,10,0"/><path d="M20,20C20,30,20,30,20,40s0,10,0,10"/></g></svg>"#;
+
+        let strokes = parse_strokes(svg).unwrap();
+
+        assert_eq!(strokes.len(), 2);
+        assert_eq!(strokes[0].number(), 1);
+        assert_eq!(strokes[0].start(), StrokePoint::new(10.0, 10.0));
+        assert_eq!(strokes[0].end(), StrokePoint::new(20.0, 10.0));
+        assert_eq!(strokes[1].end(), StrokePoint::new(20.0, 50.0));
+    }
+
+    #[test]
+    fn should_load_embedded_stroke_order() {
+        let stroke_order = STROKE_ORDER_DICTIONARY.get_stroke_order("七").unwrap();
+
+        assert_eq!(stroke_order.stroke_count(), 2);
+    }
+}
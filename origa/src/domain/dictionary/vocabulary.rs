@@ -2,7 +2,13 @@ use std::{collections::HashMap, sync::LazyLock};
 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::NativeLanguage;
+use crate::domain::{
+    dictionary::KANJI_DICTIONARY,
+    japanese::JapaneseChar,
+    knowledge::ExamplePhrase,
+    pitch_accent::PitchAccent,
+    value_objects::{JapaneseLevel, NativeLanguage},
+};
 
 pub static VOCABULARY_DICTIONARY: LazyLock<VocabularyDatabase> =
     LazyLock::new(VocabularyDatabase::new);
@@ -10,8 +16,15 @@ pub static VOCABULARY_DICTIONARY: LazyLock<VocabularyDatabase> =
 #[derive(Debug, Clone)]
 pub struct VocabularyInfo {
     word: String,
+    readings: Vec<String>,
+    level: Option<JapaneseLevel>,
     russian_translation: String,
     english_translation: String,
+    russian_examples: Vec<ExamplePhrase>,
+    english_examples: Vec<ExamplePhrase>,
+    senses: Vec<VocabularySense>,
+    frequency_tags: Vec<String>,
+    pitch_accents: Vec<PitchAccent>,
 }
 
 impl VocabularyInfo {
@@ -19,28 +32,131 @@ impl VocabularyInfo {
         &self.word
     }
 
-    pub fn russian_translation(&self) -> &str {
-        &self.russian_translation
+    /// Чтения слова каной
+    pub fn readings(&self) -> &[String] {
+        &self.readings
     }
 
-    pub fn english_translation(&self) -> &str {
-        &self.english_translation
+    /// Краткий перевод для карточки
+    pub fn translation(&self, native_language: &NativeLanguage) -> &str {
+        match native_language {
+            NativeLanguage::Russian => &self.russian_translation,
+            NativeLanguage::English => &self.english_translation,
+        }
+    }
+
+    /// Уровень JLPT, если слово есть в списках JLPT
+    pub fn level(&self) -> Option<&JapaneseLevel> {
+        self.level.as_ref()
+    }
+
+    /// Примеры из словаря с переводом на язык пользователя
+    pub fn examples(&self, native_language: &NativeLanguage) -> &[ExamplePhrase] {
+        match native_language {
+            NativeLanguage::Russian => &self.russian_examples,
+            NativeLanguage::English => &self.english_examples,
+        }
+    }
+
+    /// Ударения чтений слова, у одного чтения их может быть несколько
+    pub fn pitch_accents(&self) -> &[PitchAccent] {
+        &self.pitch_accents
+    }
+
+    /// Значения слова в порядке JMdict
+    pub fn senses(&self) -> &[VocabularySense] {
+        &self.senses
+    }
+
+    /// Метки частотности JMdict: `news1`, `ichi1`, `nf12` и другие
+    pub fn frequency_tags(&self) -> &[String] {
+        &self.frequency_tags
+    }
+
+    /// Слово входит в списки частотных слов JMdict
+    pub fn is_common(&self) -> bool {
+        self.frequency_tags.iter().any(|tag| is_common_tag(tag))
+    }
+}
+
+/// Метка одного из списков частотных слов JMdict
+pub(super) fn is_common_tag(tag: &str) -> bool {
+    matches!(tag, "news1" | "ichi1" | "spec1" | "spec2" | "gai1")
+}
+
+/// Одно значение слова со своими частями речи и пометами
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct VocabularySense {
+    #[serde(default)]
+    part_of_speech: Vec<String>,
+    #[serde(default)]
+    russian_glosses: Vec<String>,
+    #[serde(default)]
+    english_glosses: Vec<String>,
+    #[serde(default)]
+    notes: Vec<String>,
+}
+
+impl VocabularySense {
+    /// Коды частей речи JMdict: `n`, `v5k`, `adj-i` и другие
+    pub fn part_of_speech(&self) -> &[String] {
+        &self.part_of_speech
+    }
+
+    pub fn glosses(&self, native_language: &NativeLanguage) -> &[String] {
+        match native_language {
+            NativeLanguage::Russian => &self.russian_glosses,
+            NativeLanguage::English => &self.english_glosses,
+        }
+    }
+
+    /// Пометы об употреблении: `uk`, `col`, `hon` и пояснения
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    pub(super) fn push_part_of_speech(&mut self, part_of_speech: String) {
+        self.part_of_speech.push(part_of_speech);
+    }
+
+    pub(super) fn push_gloss(&mut self, native_language: &NativeLanguage, gloss: String) {
+        match native_language {
+            NativeLanguage::Russian => self.russian_glosses.push(gloss),
+            NativeLanguage::English => self.english_glosses.push(gloss),
+        }
+    }
+
+    pub(super) fn push_note(&mut self, note: String) {
+        self.notes.push(note);
     }
 }
 
-#[derive(Serialize, Deserialize)]
-struct ExamplePhraseStoredType {
+#[derive(Serialize, Deserialize, Clone)]
+pub(super) struct ExamplePhraseStoredType {
     text: String,
     translation: String,
 }
 
-#[derive(Serialize, Deserialize)]
-struct VocabularyEntryStoredType {
-    level: String,
-    russian_translation: String,
-    english_translation: String,
-    russian_examples: Vec<ExamplePhraseStoredType>,
-    english_examples: Vec<ExamplePhraseStoredType>,
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub(super) struct VocabularyEntryStoredType {
+    #[serde(default)]
+    pub(super) level: String,
+    #[serde(default)]
+    pub(super) russian_translation: String,
+    #[serde(default)]
+    pub(super) english_translation: String,
+    #[serde(default)]
+    pub(super) russian_examples: Vec<ExamplePhraseStoredType>,
+    #[serde(default)]
+    pub(super) english_examples: Vec<ExamplePhraseStoredType>,
+    #[serde(default)]
+    pub(super) readings: Vec<String>,
+    #[serde(default)]
+    pub(super) senses: Vec<VocabularySense>,
+    #[serde(default)]
+    pub(super) frequency_tags: Vec<String>,
+    #[serde(default)]
+    pub(super) pitch_accents: Vec<PitchAccent>,
 }
 
 pub struct VocabularyDatabase {
@@ -116,8 +232,15 @@ impl VocabularyDatabase {
                     word.clone(),
                     VocabularyInfo {
                         word,
+                        readings: entry.readings,
+                        level: entry.level.parse().ok(),
                         russian_translation: entry.russian_translation,
                         english_translation: entry.english_translation,
+                        russian_examples: to_example_phrases(entry.russian_examples),
+                        english_examples: to_example_phrases(entry.english_examples),
+                        senses: entry.senses,
+                        frequency_tags: entry.frequency_tags,
+                        pitch_accents: entry.pitch_accents,
                     },
                 )
             })
@@ -129,13 +252,34 @@ impl VocabularyDatabase {
     pub fn get_translation(&self, word: &str, native_language: &NativeLanguage) -> Option<String> {
         self.vocabulary_map
             .get(word)
-            .map(|info| match native_language {
-                NativeLanguage::Russian => info.russian_translation.clone(),
-                NativeLanguage::English => info.english_translation.clone(),
-            })
+            .map(|info| info.translation(native_language).to_string())
     }
 
     pub fn get_vocabulary_info(&self, word: &str) -> Option<&VocabularyInfo> {
         self.vocabulary_map.get(word)
     }
+
+    pub fn words(&self) -> impl Iterator<Item = &VocabularyInfo> {
+        self.vocabulary_map.values()
+    }
+
+    /// Уровень слова из словаря, а если его там нет — по самому сложному из кандзи
+    pub fn word_level(&self, word: &str) -> Option<JapaneseLevel> {
+        if let Some(level) = self.get_vocabulary_info(word).and_then(|info| info.level()) {
+            return Some(*level);
+        }
+
+        word.chars()
+            .filter(|c| c.is_kanji())
+            .filter_map(|c| KANJI_DICTIONARY.get_kanji_info(&c.to_string()).ok())
+            .map(|info| *info.jlpt())
+            .max()
+    }
+}
+
+fn to_example_phrases(examples: Vec<ExamplePhraseStoredType>) -> Vec<ExamplePhrase> {
+    examples
+        .into_iter()
+        .map(|example| ExamplePhrase::new(example.text, example.translation))
+        .collect()
 }
@@ -24,6 +24,8 @@ pub enum OrigaError {
     TokenizerError { reason: String },
     GrammarFormatError { reason: String },
     WellKnownSetParseError { reason: String },
+    StrokeOrderError { reason: String },
+    JmdictImportError { reason: String },
 }
 
 impl fmt::Display for OrigaError {
@@ -90,6 +92,12 @@ impl fmt::Display for OrigaError {
             OrigaError::WellKnownSetParseError { reason } => {
                 write!(f, "WellKnownSetError: {}", reason)
             }
+            OrigaError::StrokeOrderError { reason } => {
+                write!(f, "Stroke order error: {}", reason)
+            }
+            OrigaError::JmdictImportError { reason } => {
+                write!(f, "JMdict import error: {}", reason)
+            }
         }
     }
 }
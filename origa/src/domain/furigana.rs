@@ -1,16 +1,53 @@
+use serde::{Deserialize, Serialize};
+
 use crate::domain::{
-    OrigaError,
-    japanese::{JapaneseChar, JapaneseText},
-    tokenizer::tokenize_text,
+    KANJI_DICTIONARY, OrigaError, User, japanese::JapaneseChar, tokenizer::tokenize_text,
 };
 
-pub fn furiganize_text(text: &str) -> Result<String, OrigaError> {
-    let mut result = String::new();
+const ITERATION_MARK: char = '々';
+const LONG_VOWEL_MARK: char = 'ー';
+
+/// Часть текста с чтением хираганой, если в ней есть кандзи
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FuriganaSegment {
+    text: String,
+    reading: Option<String>,
+}
+
+impl FuriganaSegment {
+    pub fn new(text: String, reading: Option<String>) -> Self {
+        Self { text, reading }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reading(&self) -> Option<&str> {
+        self.reading.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuriganaFormat {
+    /// `<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>`
+    Html,
+    /// ` 漢字[かんじ]`, как в полях Anki
+    Anki,
+    /// Только чтение: `かんじ`
+    Reading,
+    /// `{漢字|かんじ}`
+    Markdown,
+}
+
+/// Разбивает текст на части, над каждой частью с кандзи ставится ее чтение
+pub fn furiganize_text(text: &str) -> Result<Vec<FuriganaSegment>, OrigaError> {
+    let mut segments = Vec::new();
     let mut current_segment = String::new();
     let mut is_current_japanese = false;
 
     for ch in text.chars() {
-        let is_japanese = ch.is_japanese();
+        let is_japanese = ch.is_japanese() || is_kanji_part(ch);
 
         if current_segment.is_empty() {
             is_current_japanese = is_japanese;
@@ -19,9 +56,9 @@ pub fn furiganize_text(text: &str) -> Result<String, OrigaError> {
             current_segment.push(ch);
         } else {
             if is_current_japanese {
-                result.push_str(&furiganize_clear_japanese(&current_segment)?);
+                furiganize_clear_japanese(&current_segment, &mut segments)?;
             } else {
-                result.push_str(&current_segment);
+                push_segment(&mut segments, &current_segment, None);
             }
             current_segment.clear();
             current_segment.push(ch);
@@ -31,35 +68,280 @@ pub fn furiganize_text(text: &str) -> Result<String, OrigaError> {
 
     if !current_segment.is_empty() {
         if is_current_japanese {
-            result.push_str(&furiganize_clear_japanese(&current_segment)?);
+            furiganize_clear_japanese(&current_segment, &mut segments)?;
         } else {
-            result.push_str(&current_segment);
+            push_segment(&mut segments, &current_segment, None);
         }
     }
 
-    Ok(result)
+    Ok(segments)
+}
+
+/// Фуригана только над кандзи, которые пользователь еще не знает. Кандзи
+/// считается известным, если его карточка изучена или его уровень JLPT
+/// проще текущего уровня пользователя.
+pub fn furiganize_for_user(text: &str, user: &User) -> Result<Vec<FuriganaSegment>, OrigaError> {
+    let known_kanji = user.knowledge_set().known_kanji();
+    let level = user.current_japanese_level();
+
+    Ok(hide_known_readings(furiganize_text(text)?, |kanji| {
+        known_kanji.contains(&kanji)
+            || KANJI_DICTIONARY
+                .get_kanji_info(&kanji.to_string())
+                .is_ok_and(|info| info.jlpt() < level)
+    }))
 }
 
-fn furiganize_clear_japanese(text: &str) -> Result<String, OrigaError> {
-    let tokens = tokenize_text(text)?;
+pub fn render_furigana(segments: &[FuriganaSegment], format: FuriganaFormat) -> String {
     let mut result = String::new();
 
-    for token in tokens {
-        let furigana = if token.orthographic_surface_form().contains_kanji() {
-            format_html(
-                token.orthographic_surface_form(),
-                token.phonological_surface_form(),
-            )
+    for segment in segments {
+        match (format, segment.reading()) {
+            (FuriganaFormat::Html, Some(reading)) => result.push_str(&format!(
+                "<ruby>{}<rp>(</rp><rt>{}</rt><rp>)</rp></ruby>",
+                segment.text, reading
+            )),
+            (FuriganaFormat::Anki, Some(reading)) => {
+                if !result.is_empty() {
+                    result.push(' ');
+                }
+                result.push_str(&format!("{}[{}]", segment.text, reading));
+            }
+            (FuriganaFormat::Reading, Some(reading)) => result.push_str(reading),
+            (FuriganaFormat::Markdown, Some(reading)) => {
+                result.push_str(&format!("{{{}|{}}}", segment.text, reading))
+            }
+            (_, None) => result.push_str(&segment.text),
+        }
+    }
+
+    result
+}
+
+fn furiganize_clear_japanese(
+    text: &str,
+    segments: &mut Vec<FuriganaSegment>,
+) -> Result<(), OrigaError> {
+    for token in tokenize_text(text)? {
+        let surface = token.orthographic_surface_form();
+        if !surface.chars().any(is_kanji_part) {
+            push_segment(segments, surface, None);
+            continue;
+        }
+
+        for (part, reading) in align_reading(surface, &token.kana_surface_form()) {
+            push_segment(segments, &part, reading);
+        }
+    }
+
+    Ok(())
+}
+
+/// Чтение части остается, если в ней есть хотя бы один неизвестный кандзи:
+/// чтение слова вроде 東京 нельзя разделить между кандзи
+fn hide_known_readings(
+    segments: Vec<FuriganaSegment>,
+    is_known: impl Fn(char) -> bool,
+) -> Vec<FuriganaSegment> {
+    let mut result = Vec::new();
+    for segment in segments {
+        let is_known_segment = segment.text.chars().filter(|c| c.is_kanji()).all(&is_known);
+        let reading = if is_known_segment {
+            None
         } else {
-            token.orthographic_surface_form().to_string()
+            segment.reading
         };
+        push_segment(&mut result, &segment.text, reading);
+    }
+    result
+}
+
+/// Соседние части без чтения склеиваются в одну
+fn push_segment(segments: &mut Vec<FuriganaSegment>, text: &str, reading: Option<String>) {
+    match (segments.last_mut(), &reading) {
+        (Some(last), None) if last.reading.is_none() => last.text.push_str(text),
+        _ => segments.push(FuriganaSegment::new(text.to_string(), reading)),
+    }
+}
+
+/// Кандзи и знаки, которые читаются как кандзи: 々 повторяет предыдущий кандзи
+fn is_kanji_part(c: char) -> bool {
+    c.is_kanji() || matches!(c, ITERATION_MARK | '〆' | 'ヶ')
+}
+
+/// Делит слово на части из кандзи и каны и подбирает чтение хираганой для каждой
+/// части из кандзи, не захватывая окуригану. Если чтение не удается разложить,
+/// как у некоторых слов с особым чтением, оно ставится над всем словом.
+fn align_reading(surface: &str, reading: &str) -> Vec<(String, Option<String>)> {
+    let mut parts: Vec<(String, bool)> = Vec::new();
+    for c in surface.chars() {
+        let is_kanji = is_kanji_part(c);
+        match parts.last_mut() {
+            Some((part, part_is_kanji)) if *part_is_kanji == is_kanji => part.push(c),
+            _ => parts.push((c.to_string(), is_kanji)),
+        }
+    }
 
-        result.push_str(&furigana);
+    let reading: Vec<char> = reading.chars().map(|c| c.to_hiragana()).collect();
+    match align(&parts, &reading) {
+        Some(readings) => parts
+            .into_iter()
+            .zip(readings)
+            .map(|((part, is_kanji), reading)| (part, is_kanji.then(|| reading.iter().collect())))
+            .collect(),
+        None => vec![(surface.to_string(), Some(reading.into_iter().collect()))],
     }
+}
+
+/// Части из каны должны совпасть с чтением, частям из кандзи достается
+/// кратчайшее чтение, при котором совпадает остаток слова
+fn align<'a>(parts: &[(String, bool)], reading: &'a [char]) -> Option<Vec<&'a [char]>> {
+    let Some(((part, is_kanji), rest)) = parts.split_first() else {
+        return reading.is_empty().then(Vec::new);
+    };
+
+    let lengths = if *is_kanji {
+        1..=reading.len()
+    } else {
+        let kana: Vec<char> = part.chars().map(|c| c.to_hiragana()).collect();
+        if reading.len() < kana.len()
+            || !kana.iter().zip(reading).all(|(k, r)| kana_matches(*k, *r))
+        {
+            return None;
+        }
+        kana.len()..=kana.len()
+    };
+
+    lengths.into_iter().find_map(|len| {
+        let mut readings = align(rest, &reading[len..])?;
+        readings.insert(0, &reading[..len]);
+        Some(readings)
+    })
+}
 
-    Ok(result)
+/// ー остается в чтении, если для него не нашлось каны словарного чтения,
+/// и может означать любую долгую гласную
+fn kana_matches(kana: char, reading: char) -> bool {
+    kana == reading || (reading == LONG_VOWEL_MARK && "あいうえおー".contains(kana))
 }
 
-fn format_html(base: &str, text: &str) -> String {
-    format!("<ruby>{base}<rp>(</rp><rt>{text}</rt><rp>)</rp></ruby>")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tokenizer::restore_long_vowels;
+
+    /// Выравнивание по кане словарной формы из произношения и чтения UniDic
+    fn aligned(
+        surface: &str,
+        pronunciation: &str,
+        lexeme_reading: &str,
+    ) -> Vec<(String, Option<String>)> {
+        align_reading(
+            surface,
+            &restore_long_vowels(pronunciation, pronunciation, lexeme_reading),
+        )
+    }
+
+    fn part(text: &str, reading: Option<&str>) -> (String, Option<String>) {
+        (text.to_string(), reading.map(str::to_string))
+    }
+
+    #[test]
+    fn should_exclude_okurigana_from_reading() {
+        assert_eq!(
+            aligned("食べる", "タベル", "タベル"),
+            vec![part("食", Some("た")), part("べる", None)]
+        );
+        assert_eq!(
+            aligned("取り扱い", "トリアツカイ", "トリアツカイ"),
+            vec![
+                part("取", Some("と")),
+                part("り", None),
+                part("扱", Some("あつか")),
+                part("い", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_take_long_vowels_from_lexeme_reading() {
+        assert_eq!(
+            aligned("美味しい", "オイシー", "オイシイ"),
+            vec![part("美味", Some("おい")), part("しい", None)]
+        );
+        assert_eq!(
+            aligned("東京", "トーキョー", "トウキョウ"),
+            vec![part("東京", Some("とうきょう"))]
+        );
+        assert_eq!(
+            aligned("先生", "センセー", "センセイ"),
+            vec![part("先生", Some("せんせい"))]
+        );
+        assert_eq!(
+            aligned("大きい", "オーキー", "オオキイ"),
+            vec![part("大", Some("おお")), part("きい", None)]
+        );
+        assert_eq!(
+            aligned("通り", "トーリ", "トオリ"),
+            vec![part("通", Some("とお")), part("り", None)]
+        );
+    }
+
+    #[test]
+    fn should_keep_iteration_mark_and_special_readings_in_one_part() {
+        assert_eq!(
+            aligned("人々", "ヒトビト", "ヒトビト"),
+            vec![part("人々", Some("ひとびと"))]
+        );
+        assert_eq!(
+            aligned("今日", "キョー", "キョウ"),
+            vec![part("今日", Some("きょう"))]
+        );
+    }
+
+    #[test]
+    fn should_render_segments_in_every_format() {
+        let segments = vec![
+            FuriganaSegment::new("私".to_string(), Some("わたし".to_string())),
+            FuriganaSegment::new("は".to_string(), None),
+            FuriganaSegment::new("食".to_string(), Some("た".to_string())),
+            FuriganaSegment::new("べる".to_string(), None),
+        ];
+
+        assert_eq!(
+            render_furigana(&segments, FuriganaFormat::Html),
+            "<ruby>私<rp>(</rp><rt>わたし</rt><rp>)</rp></ruby>は<ruby>食<rp>(</rp><rt>た</rt><rp>)</rp></ruby>べる"
+        );
+        assert_eq!(
+            render_furigana(&segments, FuriganaFormat::Anki),
+            "私[わたし]は 食[た]べる"
+        );
+        assert_eq!(
+            render_furigana(&segments, FuriganaFormat::Reading),
+            "わたしはたべる"
+        );
+        assert_eq!(
+            render_furigana(&segments, FuriganaFormat::Markdown),
+            "{私|わたし}は{食|た}べる"
+        );
+    }
+
+    #[test]
+    fn should_hide_readings_of_known_kanji() {
+        let segments = vec![
+            FuriganaSegment::new("私".to_string(), Some("わたし".to_string())),
+            FuriganaSegment::new("は".to_string(), None),
+            FuriganaSegment::new("東京".to_string(), Some("とうきょう".to_string())),
+        ];
+
+        let segments = hide_known_readings(segments, |kanji| matches!(kanji, '私' | '東'));
+
+        assert_eq!(
+            segments,
+            vec![
+                FuriganaSegment::new("私は".to_string(), None),
+                FuriganaSegment::new("東京".to_string(), Some("とうきょう".to_string())),
+            ]
+        );
+    }
 }
@@ -0,0 +1,303 @@
+use std::sync::LazyLock;
+
+use crate::domain::{OrigaError, Rating, STROKE_ORDER_DICTIONARY, StrokePoint};
+
+const RESAMPLED_POINTS: usize = 8;
+const MAX_STROKE_COUNT_DIFF: usize = 2;
+const HARD_RANK: usize = 5;
+const MIN_SIZE: f64 = 1e-6;
+
+pub static HANDWRITING_RECOGNIZER: LazyLock<HandwritingRecognizer> =
+    LazyLock::new(HandwritingRecognizer::new);
+
+/// Кандзи, похожий на нарисованный, и степень похожести от 0 до 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HandwritingCandidate {
+    kanji: char,
+    score: f64,
+}
+
+impl HandwritingCandidate {
+    pub fn kanji(&self) -> char {
+        self.kanji
+    }
+
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+/// Результат проверки написанного кандзи
+#[derive(Debug, Clone, PartialEq)]
+pub struct WritingCheck {
+    expected: char,
+    rank: Option<usize>,
+    stroke_count_matches: bool,
+    candidates: Vec<HandwritingCandidate>,
+}
+
+impl WritingCheck {
+    /// Нарисованное лучше всего похоже на ожидаемый кандзи
+    pub fn is_correct(&self) -> bool {
+        self.rank == Some(0)
+    }
+
+    pub fn stroke_count_matches(&self) -> bool {
+        self.stroke_count_matches
+    }
+
+    /// Похожесть нарисованного на ожидаемый кандзи
+    pub fn score(&self) -> f64 {
+        self.candidates
+            .iter()
+            .find(|candidate| candidate.kanji == self.expected)
+            .map(|candidate| candidate.score)
+            .unwrap_or(0.0)
+    }
+
+    /// Самые похожие кандзи, чтобы показать, что получилось вместо ожидаемого
+    pub fn candidates(&self) -> &[HandwritingCandidate] {
+        &self.candidates
+    }
+
+    pub fn suggested_rating(&self) -> Rating {
+        match self.rank {
+            Some(0) if self.stroke_count_matches => Rating::Good,
+            Some(rank) if rank < HARD_RANK => Rating::Hard,
+            _ => Rating::Again,
+        }
+    }
+}
+
+struct KanjiTemplate {
+    kanji: char,
+    strokes: Vec<Vec<StrokePoint>>,
+}
+
+/// Распознает кандзи по чертам пользователя без сети и моделей: черты
+/// сравниваются с эталонными по порядку, каждая черта приводится к нескольким
+/// точкам, равномерно распределенным по ее длине. Расстояние между
+/// соответствующими точками учитывает и положение, и направление черты.
+pub struct HandwritingRecognizer {
+    templates: Vec<KanjiTemplate>,
+}
+
+impl Default for HandwritingRecognizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandwritingRecognizer {
+    pub fn new() -> Self {
+        let templates = STROKE_ORDER_DICTIONARY
+            .stroke_orders()
+            .map(|stroke_order| KanjiTemplate {
+                kanji: stroke_order.kanji(),
+                strokes: normalize(
+                    &stroke_order
+                        .strokes()
+                        .iter()
+                        .map(|stroke| stroke.points().to_vec())
+                        .collect::<Vec<_>>(),
+                ),
+            })
+            .collect();
+
+        Self { templates }
+    }
+
+    /// Возвращает не больше `limit` кандзи, отсортированных по похожести.
+    /// Черты передаются в порядке написания, координаты могут быть любыми.
+    pub fn recognize(
+        &self,
+        strokes: &[Vec<StrokePoint>],
+        limit: usize,
+    ) -> Result<Vec<HandwritingCandidate>, OrigaError> {
+        if strokes.is_empty() || strokes.iter().any(|stroke| stroke.is_empty()) {
+            return Err(OrigaError::InvalidValues {
+                reason: "Strokes must not be empty".to_string(),
+            });
+        }
+
+        let strokes = normalize(strokes);
+
+        let mut candidates: Vec<_> = self
+            .templates
+            .iter()
+            .filter_map(|template| {
+                Some(HandwritingCandidate {
+                    kanji: template.kanji,
+                    score: similarity(&strokes, &template.strokes)?,
+                })
+            })
+            .collect();
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.kanji.cmp(&b.kanji)));
+        candidates.truncate(limit);
+
+        Ok(candidates)
+    }
+
+    /// Проверяет, что пользователь написал именно `kanji`
+    pub fn check_writing(
+        &self,
+        kanji: &str,
+        strokes: &[Vec<StrokePoint>],
+    ) -> Result<WritingCheck, OrigaError> {
+        let stroke_order = STROKE_ORDER_DICTIONARY.get_stroke_order(kanji)?;
+        let candidates = self.recognize(strokes, HARD_RANK)?;
+
+        Ok(WritingCheck {
+            expected: stroke_order.kanji(),
+            rank: candidates
+                .iter()
+                .position(|candidate| candidate.kanji == stroke_order.kanji()),
+            stroke_count_matches: stroke_order.stroke_count() == strokes.len(),
+            candidates,
+        })
+    }
+}
+
+/// Похожесть черт по порядку с поправкой на разницу в их количестве.
+/// Кандзи со слишком отличающимся числом черт не рассматриваются.
+fn similarity(strokes: &[Vec<StrokePoint>], template: &[Vec<StrokePoint>]) -> Option<f64> {
+    if strokes.len().abs_diff(template.len()) > MAX_STROKE_COUNT_DIFF {
+        return None;
+    }
+
+    let compared = strokes.len().min(template.len());
+    let distance = strokes
+        .iter()
+        .zip(template)
+        .map(|(stroke, expected)| {
+            stroke
+                .iter()
+                .zip(expected)
+                .map(|(a, b)| (a.x() - b.x()).hypot(a.y() - b.y()))
+                .sum::<f64>()
+                / RESAMPLED_POINTS as f64
+        })
+        .sum::<f64>()
+        / compared as f64;
+
+    let stroke_count_ratio = compared as f64 / strokes.len().max(template.len()) as f64;
+
+    Some((1.0 - distance).clamp(0.0, 1.0) * stroke_count_ratio)
+}
+
+/// Вписывает кандзи в единичный квадрат с сохранением пропорций
+/// и приводит каждую черту к `RESAMPLED_POINTS` точкам
+fn normalize(strokes: &[Vec<StrokePoint>]) -> Vec<Vec<StrokePoint>> {
+    let points = strokes.iter().flatten();
+    let min_x = points.clone().map(|p| p.x()).fold(f64::INFINITY, f64::min);
+    let max_x = points
+        .clone()
+        .map(|p| p.x())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = points.clone().map(|p| p.y()).fold(f64::INFINITY, f64::min);
+    let max_y = points.map(|p| p.y()).fold(f64::NEG_INFINITY, f64::max);
+
+    let size = (max_x - min_x).max(max_y - min_y).max(MIN_SIZE);
+    let center_x = (min_x + max_x) / 2.0;
+    let center_y = (min_y + max_y) / 2.0;
+
+    strokes
+        .iter()
+        .map(|stroke| {
+            let stroke: Vec<_> = stroke
+                .iter()
+                .map(|p| {
+                    StrokePoint::new(
+                        (p.x() - center_x) / size + 0.5,
+                        (p.y() - center_y) / size + 0.5,
+                    )
+                })
+                .collect();
+            resample(&stroke)
+        })
+        .collect()
+}
+
+fn resample(stroke: &[StrokePoint]) -> Vec<StrokePoint> {
+    let distance = |a: &StrokePoint, b: &StrokePoint| (a.x() - b.x()).hypot(a.y() - b.y());
+    let length: f64 = stroke.windows(2).map(|w| distance(&w[0], &w[1])).sum();
+
+    if length < MIN_SIZE {
+        return vec![stroke[0]; RESAMPLED_POINTS];
+    }
+
+    let step = length / (RESAMPLED_POINTS - 1) as f64;
+    let mut points = vec![stroke[0]];
+    let mut passed = 0.0;
+    for pair in stroke.windows(2) {
+        let segment = distance(&pair[0], &pair[1]);
+        while segment > 0.0 && points.len() < RESAMPLED_POINTS - 1 {
+            let target = step * points.len() as f64;
+            if target > passed + segment {
+                break;
+            }
+            let t = (target - passed) / segment;
+            points.push(StrokePoint::new(
+                pair[0].x() + (pair[1].x() - pair[0].x()) * t,
+                pair[0].y() + (pair[1].y() - pair[0].y()) * t,
+            ));
+        }
+        passed += segment;
+    }
+    points.push(stroke[stroke.len() - 1]);
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_strokes(kanji: &str) -> Vec<Vec<StrokePoint>> {
+        STROKE_ORDER_DICTIONARY
+            .get_stroke_order(kanji)
+            .unwrap()
+            .strokes()
+            .iter()
+            .map(|stroke| {
+                stroke
+                    .points()
+                    .iter()
+                    .map(|p| StrokePoint::new(p.x() * 3.0 + 20.0, p.y() * 3.0 + 10.0))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_recognize_scaled_kanji() {
+        let candidates = HANDWRITING_RECOGNIZER
+            .recognize(&user_strokes("語"), 5)
+            .unwrap();
+
+        assert_eq!(candidates[0].kanji(), '語');
+    }
+
+    #[test]
+    fn should_not_accept_strokes_in_reverse_order() {
+        let mut strokes = user_strokes("右");
+        strokes.reverse();
+
+        let check = HANDWRITING_RECOGNIZER
+            .check_writing("右", &strokes)
+            .unwrap();
+
+        assert!(!check.is_correct());
+    }
+
+    #[test]
+    fn should_resample_to_fixed_point_count() {
+        let stroke = vec![StrokePoint::new(0.0, 0.0), StrokePoint::new(7.0, 0.0)];
+
+        let points = resample(&stroke);
+
+        assert_eq!(points.len(), RESAMPLED_POINTS);
+        assert_eq!(points[3], StrokePoint::new(3.0, 0.0));
+    }
+}
@@ -1,10 +1,15 @@
-use crate::domain::{OrigaError, furigana::furiganize_text};
+use crate::domain::{
+    OrigaError,
+    furigana::{FuriganaFormat, furiganize_text, render_furigana},
+    tokenizer::tokenize_text,
+};
 
 pub trait JapaneseChar {
     fn is_japanese(&self) -> bool;
     fn is_hiragana(&self) -> bool;
     fn is_katakana(&self) -> bool;
     fn is_kanji(&self) -> bool;
+    fn to_hiragana(&self) -> char;
 }
 
 pub trait JapaneseText {
@@ -15,6 +20,8 @@ pub trait JapaneseText {
     fn has_furigana(&self) -> Result<bool, OrigaError>;
     fn as_furigana(&self) -> Result<String, OrigaError>;
     fn equals_by_reading(&self, other: &Self) -> Result<bool, OrigaError>;
+    fn to_hiragana(&self) -> String;
+    fn as_reading(&self) -> Result<String, OrigaError>;
 }
 
 impl JapaneseChar for char {
@@ -35,6 +42,14 @@ impl JapaneseChar for char {
             || ('\u{3400}'..='\u{4DBF}').contains(self)
             || ('\u{20000}'..='\u{2A6DF}').contains(self)
     }
+
+    fn to_hiragana(&self) -> char {
+        if ('\u{30A1}'..='\u{30F6}').contains(self) {
+            char::from_u32(*self as u32 - 0x60).unwrap_or(*self)
+        } else {
+            *self
+        }
+    }
 }
 
 impl JapaneseText for str {
@@ -47,7 +62,7 @@ impl JapaneseText for str {
     }
 
     fn as_furigana(&self) -> Result<String, OrigaError> {
-        furiganize_text(self)
+        furiganize_text(self).map(|segments| render_furigana(&segments, FuriganaFormat::Html))
     }
 
     fn has_furigana(&self) -> Result<bool, OrigaError> {
@@ -63,6 +78,20 @@ impl JapaneseText for str {
     fn contains_kanji(&self) -> bool {
         self.chars().any(|c| c.is_kanji())
     }
+
+    fn to_hiragana(&self) -> String {
+        self.chars().map(|c| c.to_hiragana()).collect()
+    }
+
+    /// Чтение текста хираганой по данным токенизатора
+    fn as_reading(&self) -> Result<String, OrigaError> {
+        let reading: String = tokenize_text(self)?
+            .iter()
+            .map(|token| token.kana_surface_form())
+            .collect();
+
+        Ok(reading.to_hiragana())
+    }
 }
 
 pub fn filter_japanese_text(text: &str) -> String {
@@ -1,18 +1,26 @@
 use crate::domain::{
-    NativeLanguage, OrigaError, ReviewLog, get_rule_by_id,
-    knowledge::{GrammarRuleCard, KanjiCard, VocabularyCard},
-    memory::{MemoryHistory, MemoryState},
+    AnswerCheck, JapaneseChar, JapaneseText, NativeLanguage, OrigaError, Quiz, QuizKind, ReviewLog,
+    check_answer, get_rule_by_id,
+    knowledge::{
+        ClozeCard, ExamplePhrase, GrammarRuleCard, KanjiCard, SentenceCard, VocabularyCard,
+    },
+    memory::{CardVariant, MemoryHistory, MemoryState},
     value_objects::{Answer, Question},
 };
-use rand::seq::SliceRandom;
+use rand::seq::{IndexedRandom, SliceRandom};
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
+const CLOZE_PROBABILITY: f64 = 1.0 / 3.0;
+const READING_QUIZ_PROBABILITY: f64 = 0.5;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StudyCard {
     card_id: Ulid,
     card: Card,
     memory_history: MemoryHistory,
+    #[serde(default)]
+    suspended: bool,
 }
 
 impl StudyCard {
@@ -21,6 +29,7 @@ impl StudyCard {
             card_id: Ulid::new(),
             card,
             memory_history: MemoryHistory::default(),
+            suspended: false,
         }
     }
 
@@ -36,51 +45,188 @@ impl StudyCard {
         &self.memory_history
     }
 
+    /// Карта исключена из уроков и закрепления
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
     pub(crate) fn add_review(&mut self, memory_state: MemoryState, review: ReviewLog) {
         self.memory_history.add_review(memory_state, review);
     }
 
+    pub(crate) fn undo_last_review(&mut self) -> Option<ReviewLog> {
+        self.memory_history.undo_last_review()
+    }
+
+    pub(crate) fn set_card(&mut self, card: Card) {
+        self.card = card;
+    }
+
+    pub(crate) fn set_suspended(&mut self, suspended: bool) {
+        self.suspended = suspended;
+    }
+
+    /// Сбрасывает прогресс изучения, карта снова становится новой
+    pub(crate) fn reset(&mut self) {
+        self.memory_history = MemoryHistory::default();
+        self.suspended = false;
+    }
+
     pub fn shuffle_card(
         &self,
         lang: &NativeLanguage,
         known_grammars: &[GrammarRuleCard],
-    ) -> Result<Card, OrigaError> {
-        let mut content = self.card().clone();
-
+    ) -> Result<LessonCard, OrigaError> {
         if !self.memory().is_known_card() && !self.memory().is_in_progress() {
-            return Ok(content);
+            return self.lesson_card(CardVariant::Original, lang);
         }
 
-        content = match &content {
+        if let Card::Vocabulary(vocab) = &self.card
+            && self.memory().is_in_progress()
+            && rand::random_bool(CLOZE_PROBABILITY)
+            && let Some(phrase_index) = vocab.cloze_phrase_indexes().choose(&mut rand::rng())
+        {
+            return self.lesson_card(
+                CardVariant::Cloze {
+                    phrase_index: *phrase_index,
+                },
+                lang,
+            );
+        }
+
+        let variant = match &self.card {
             Card::Vocabulary(vocab) => match rand::random_bool(0.5) {
-                false => {
-                    let reverted = vocab.revert()?;
-                    Card::Vocabulary(reverted)
-                }
+                false => CardVariant::Reversed,
                 true => {
                     let word_part = vocab.part_of_speech()?;
 
                     let mut rules: Vec<_> = known_grammars
                         .iter()
                         .filter(|g| g.apply_to().contains(&word_part))
+                        .filter(|g| get_rule_by_id(g.rule_id()).is_some())
                         .collect();
 
                     rules.shuffle(&mut rand::rng());
 
-                    if let Some(rule) = rules.first()
-                        && let Some(rule) = get_rule_by_id(rule.rule_id())
-                    {
-                        let vocab_with_rule = vocab.with_grammar_rule(rule, lang)?;
-                        Card::Vocabulary(vocab_with_rule)
-                    } else {
-                        content
-                    }
+                    rules
+                        .first()
+                        .map(|rule| CardVariant::GrammarRule {
+                            rule_id: *rule.rule_id(),
+                        })
+                        .unwrap_or(CardVariant::Original)
                 }
             },
-            _ => content,
+            Card::Kanji(kanji) if rand::random_bool(0.5) && kanji.stroke_order().is_ok() => {
+                CardVariant::Writing
+            }
+            _ => CardVariant::Original,
         };
 
-        Ok(content)
+        self.lesson_card(variant, lang)
+    }
+
+    /// Карточка в указанном варианте показа
+    pub fn lesson_card(
+        &self,
+        variant: CardVariant,
+        lang: &NativeLanguage,
+    ) -> Result<LessonCard, OrigaError> {
+        let card = match (&self.card, variant) {
+            (card, CardVariant::Original) => card.clone(),
+            (Card::Vocabulary(vocab), CardVariant::Reversed) => Card::Vocabulary(vocab.revert()?),
+            (Card::Vocabulary(vocab), CardVariant::GrammarRule { rule_id }) => {
+                let rule = get_rule_by_id(&rule_id).ok_or(OrigaError::InvalidValues {
+                    reason: format!("Grammar rule {} not found", rule_id),
+                })?;
+                Card::Vocabulary(vocab.with_grammar_rule(rule, lang)?)
+            }
+            // Примеры могли измениться после показа карточки, тогда номер
+            // фразы уже не подходит и карточка показывается как обычно
+            (Card::Vocabulary(vocab), CardVariant::Cloze { phrase_index }) => {
+                match vocab.cloze(phrase_index) {
+                    Ok(cloze) => Card::Cloze(cloze),
+                    Err(_) => {
+                        return Ok(LessonCard::new(
+                            self.card_id,
+                            self.card.clone(),
+                            CardVariant::Original,
+                        ));
+                    }
+                }
+            }
+            (Card::Kanji(kanji), CardVariant::Writing) => {
+                kanji.stroke_order()?;
+                Card::Kanji(kanji.clone())
+            }
+            (card, variant) => {
+                return Err(OrigaError::InvalidValues {
+                    reason: format!(
+                        "Variant {:?} is not supported for card {}",
+                        variant,
+                        card.question().text()
+                    ),
+                });
+            }
+        };
+
+        Ok(LessonCard::new(self.card_id, card, variant))
+    }
+}
+
+/// Карточка в том виде, в котором она будет показана в уроке
+#[derive(Debug, Clone, PartialEq)]
+pub struct LessonCard {
+    card_id: Ulid,
+    card: Card,
+    variant: CardVariant,
+    quiz: Option<Quiz>,
+}
+
+impl LessonCard {
+    pub fn new(card_id: Ulid, card: Card, variant: CardVariant) -> Self {
+        Self {
+            card_id,
+            card,
+            variant,
+            quiz: None,
+        }
+    }
+
+    pub fn card_id(&self) -> &Ulid {
+        &self.card_id
+    }
+
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    pub fn variant(&self) -> CardVariant {
+        self.variant
+    }
+
+    /// Варианты ответа, если карточка показывается как выбор из нескольких
+    pub fn quiz(&self) -> Option<&Quiz> {
+        self.quiz.as_ref()
+    }
+
+    pub(crate) fn set_quiz(&mut self, quiz: Option<Quiz>) {
+        self.quiz = quiz;
+    }
+
+    /// Вид вопроса с вариантами, подходящий для этой карточки.
+    /// Для слов с кандзи вместо значения иногда спрашивается чтение
+    pub(crate) fn quiz_kind(&self) -> Option<QuizKind> {
+        match (&self.card, self.variant) {
+            (Card::Vocabulary(vocabulary), CardVariant::Original)
+                if vocabulary.word().text().contains_kanji()
+                    && rand::random_bool(READING_QUIZ_PROBABILITY) =>
+            {
+                Some(QuizKind::Reading)
+            }
+            (Card::Vocabulary(_), CardVariant::Original) => Some(QuizKind::Meaning),
+            (Card::Vocabulary(_), CardVariant::Reversed) => Some(QuizKind::Word),
+            _ => None,
+        }
     }
 }
 
@@ -89,6 +235,9 @@ pub enum Card {
     Vocabulary(VocabularyCard),
     Kanji(KanjiCard),
     Grammar(GrammarRuleCard),
+    Sentence(SentenceCard),
+    /// Слово с пропуском в примере фразы, создается только для показа в уроке
+    Cloze(ClozeCard),
 }
 
 impl Card {
@@ -97,6 +246,8 @@ impl Card {
             Card::Vocabulary(card) => card.word(),
             Card::Kanji(card) => card.kanji(),
             Card::Grammar(card) => card.title(),
+            Card::Sentence(card) => card.sentence(),
+            Card::Cloze(card) => card.sentence(),
         }
     }
 
@@ -105,6 +256,82 @@ impl Card {
             Card::Vocabulary(card) => card.meaning(),
             Card::Kanji(card) => card.description(),
             Card::Grammar(card) => card.description(),
+            Card::Sentence(card) => card.translation(),
+            Card::Cloze(card) => card.answer(),
+        }
+    }
+
+    /// Проверяет введенный ответ: подходит значение карточки
+    /// или чтение вопроса (у пропуска — скрытого слова), если в нем есть кандзи
+    pub fn check_answer(&self, typed: &str) -> AnswerCheck {
+        let mut expected = vec![self.answer().text().to_string()];
+        let text_with_reading = match self {
+            Card::Cloze(card) => card.answer().text(),
+            card => card.question().text(),
+        };
+        if text_with_reading.contains_kanji()
+            && let Ok(reading) = text_with_reading.as_reading()
+        {
+            expected.push(reading);
         }
+
+        check_answer(&expected, typed)
+    }
+
+    /// Кандзи, по которым карточка связана с другими карточками
+    pub fn related_kanji(&self) -> Vec<char> {
+        match self {
+            Card::Vocabulary(card) => card
+                .word()
+                .text()
+                .chars()
+                .filter(|c| c.is_kanji())
+                .collect(),
+            Card::Kanji(card) => card
+                .kanji()
+                .text()
+                .chars()
+                .filter(|c| c.is_kanji())
+                .collect(),
+            Card::Sentence(card) => card
+                .words()
+                .iter()
+                .flat_map(|word| word.chars())
+                .filter(|c| c.is_kanji())
+                .collect(),
+            Card::Cloze(card) => card.word().chars().filter(|c| c.is_kanji()).collect(),
+            Card::Grammar(_) => Vec::new(),
+        }
+    }
+
+    /// Возвращает копию карточки с измененным вопросом, ответом и примерами.
+    /// Примеры фраз есть только у карточек словаря.
+    pub(crate) fn edited(
+        &self,
+        question: Option<Question>,
+        answer: Option<Answer>,
+        example_phrases: Option<Vec<ExamplePhrase>>,
+        native_language: &NativeLanguage,
+    ) -> Result<Card, OrigaError> {
+        let mut card = self.clone();
+
+        match &mut card {
+            Card::Vocabulary(vocabulary) => vocabulary.edit(question, answer, example_phrases),
+            Card::Kanji(_) | Card::Grammar(_) | Card::Sentence(_) if example_phrases.is_some() => {
+                return Err(OrigaError::InvalidValues {
+                    reason: "Example phrases can be edited only for vocabulary cards".to_string(),
+                });
+            }
+            Card::Kanji(kanji) => kanji.edit(question, answer, native_language)?,
+            Card::Grammar(grammar) => grammar.edit(question, answer),
+            Card::Sentence(sentence) => sentence.edit(question, answer)?,
+            Card::Cloze(_) => {
+                return Err(OrigaError::InvalidValues {
+                    reason: "Cloze cards are generated from vocabulary cards".to_string(),
+                });
+            }
+        }
+
+        Ok(card)
     }
 }
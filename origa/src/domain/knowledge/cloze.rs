@@ -0,0 +1,112 @@
+use crate::domain::{
+    OrigaError,
+    knowledge::ExamplePhrase,
+    tokenizer::tokenize_text,
+    value_objects::{Answer, Question},
+};
+use serde::{Deserialize, Serialize};
+
+const CLOZE_BLANK: &str = "＿＿＿";
+
+/// Пример фразы, в котором изучаемое слово заменено пропуском
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClozeCard {
+    sentence: Question,
+    answer: Answer,
+    word: String,
+    translation: String,
+}
+
+impl ClozeCard {
+    /// Ищет слово во фразе по словарной форме и скрывает его форму,
+    /// в которой оно стоит в предложении
+    pub fn new(word: &str, phrase: &ExamplePhrase) -> Result<Self, OrigaError> {
+        let tokens = tokenize_text(phrase.text())?;
+        let (sentence, surface_form) = blank_token(
+            phrase.text(),
+            tokens.iter().map(|token| {
+                (
+                    token.orthographic_surface_form(),
+                    token.orthographic_base_form() == word
+                        || token.orthographic_surface_form() == word,
+                )
+            }),
+        )
+        .ok_or(OrigaError::InvalidValues {
+            reason: format!("Word {} not found in phrase {}", word, phrase.text()),
+        })?;
+
+        Ok(Self {
+            sentence: Question::new(sentence)?,
+            answer: Answer::new(surface_form.to_string())?,
+            word: word.to_string(),
+            translation: phrase.translation().to_string(),
+        })
+    }
+
+    pub fn sentence(&self) -> &Question {
+        &self.sentence
+    }
+
+    /// Скрытая форма слова
+    pub fn answer(&self) -> &Answer {
+        &self.answer
+    }
+
+    /// Словарная форма скрытого слова
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn translation(&self) -> &str {
+        &self.translation
+    }
+}
+
+/// Заменяет пропуском форму первого подходящего токена. Токены ищутся в тексте
+/// по порядку, поэтому слово внутри другого слова, как 日 в 日曜日, не скрывается
+fn blank_token<'a>(
+    text: &str,
+    tokens: impl IntoIterator<Item = (&'a str, bool)>,
+) -> Option<(String, &'a str)> {
+    let mut offset = 0;
+    for (surface_form, is_word) in tokens {
+        if surface_form.is_empty() {
+            continue;
+        }
+        let Some(position) = text[offset..].find(surface_form) else {
+            continue;
+        };
+
+        let start = offset + position;
+        offset = start + surface_form.len();
+        if is_word {
+            let sentence = format!("{}{}{}", &text[..start], CLOZE_BLANK, &text[offset..]);
+            return Some((sentence, surface_form));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_blank_matching_token_instead_of_substring() {
+        let tokens = [
+            ("日曜日", false),
+            ("は", false),
+            ("いい", false),
+            ("日", true),
+            ("だ", false),
+        ];
+
+        assert_eq!(
+            blank_token("日曜日はいい日だ。", tokens),
+            Some(("日曜日はいい＿＿＿だ。".to_string(), "日"))
+        );
+        assert_eq!(blank_token("日曜日", [("日曜日", false)]), None);
+    }
+}
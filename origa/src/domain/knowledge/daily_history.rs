@@ -95,6 +95,18 @@ impl DailyHistoryItem {
         self.lessons_completed += 1;
     }
 
+    /// Возвращает счетчики из сохраненной копии, длительность занятий не меняется
+    pub(crate) fn restore_counters(&mut self, previous: &DailyHistoryItem) {
+        self.avg_stability = previous.avg_stability;
+        self.avg_difficulty = previous.avg_difficulty;
+        self.total_words = previous.total_words;
+        self.new_words = previous.new_words;
+        self.known_words = previous.known_words;
+        self.in_progress_words = previous.in_progress_words;
+        self.high_difficulty_words = previous.high_difficulty_words;
+        self.lessons_completed = previous.lessons_completed;
+    }
+
     pub fn add_lesson_duration(&mut self, lesson_duration: Duration) {
         self.total_duration += lesson_duration;
     }
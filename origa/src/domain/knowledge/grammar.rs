@@ -37,6 +37,15 @@ impl GrammarRuleCard {
         &self.description
     }
 
+    pub(crate) fn edit(&mut self, title: Option<Question>, description: Option<Answer>) {
+        if let Some(title) = title {
+            self.title = title;
+        }
+        if let Some(description) = description {
+            self.description = description;
+        }
+    }
+
     pub fn apply_to(&self) -> &[PartOfSpeech] {
         &self.apply_to
     }
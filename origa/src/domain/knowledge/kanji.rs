@@ -1,6 +1,6 @@
 use crate::domain::{
-    Answer, JapaneseLevel, KANJI_DICTIONARY, NativeLanguage, OrigaError, Question, RadicalInfo,
-    VOCABULARY_DICTIONARY,
+    Answer, JapaneseLevel, KANJI_DICTIONARY, KanjiStrokeOrder, NativeLanguage, OrigaError,
+    Question, RadicalInfo, STROKE_ORDER_DICTIONARY, VOCABULARY_DICTIONARY,
 };
 use serde::{Deserialize, Serialize};
 
@@ -19,24 +19,21 @@ pub struct ExampleKanjiWord {
 
 impl KanjiCard {
     pub fn new(kanji: String, native_language: &NativeLanguage) -> Result<Self, OrigaError> {
+        Self::with_meanings(kanji, |word| word_meaning(word, native_language))
+    }
+
+    fn with_meanings(
+        kanji: String,
+        meaning_of: impl Fn(&str) -> String,
+    ) -> Result<Self, OrigaError> {
         let kanji_info = KANJI_DICTIONARY.get_kanji_info(&kanji)?;
         let description = kanji_info.description();
         let example_words = kanji_info
             .popular_words()
             .iter()
-            .map(|word| {
-                let meaning = VOCABULARY_DICTIONARY
-                    .get_vocabulary_info(word)
-                    .map(|kanji_info| match native_language {
-                        NativeLanguage::Russian => kanji_info.russian_translation().to_string(),
-                        NativeLanguage::English => kanji_info.english_translation().to_string(),
-                    })
-                    .unwrap_or_default();
-
-                ExampleKanjiWord {
-                    word: word.clone(),
-                    meaning,
-                }
+            .map(|word| ExampleKanjiWord {
+                word: word.clone(),
+                meaning: meaning_of(word),
             })
             .collect();
 
@@ -59,6 +56,33 @@ impl KanjiCard {
         &self.example_words
     }
 
+    /// Смена кандзи собирает описание и примеры слов заново, как при создании
+    pub(crate) fn edit(
+        &mut self,
+        kanji: Option<Question>,
+        description: Option<Answer>,
+        native_language: &NativeLanguage,
+    ) -> Result<(), OrigaError> {
+        self.edit_with_meanings(kanji, description, |word| {
+            word_meaning(word, native_language)
+        })
+    }
+
+    fn edit_with_meanings(
+        &mut self,
+        kanji: Option<Question>,
+        description: Option<Answer>,
+        meaning_of: impl Fn(&str) -> String,
+    ) -> Result<(), OrigaError> {
+        if let Some(kanji) = kanji.filter(|kanji| *kanji != self.kanji) {
+            *self = Self::with_meanings(kanji.text().to_string(), meaning_of)?;
+        }
+        if let Some(description) = description {
+            self.description = description;
+        }
+        Ok(())
+    }
+
     pub fn jlpt(&self) -> JapaneseLevel {
         KANJI_DICTIONARY
             .get_kanji_info(self.kanji.text())
@@ -73,11 +97,50 @@ impl KanjiCard {
             .unwrap_or(0)
     }
 
+    pub fn onyomi(&self) -> Vec<String> {
+        KANJI_DICTIONARY
+            .get_kanji_info(self.kanji.text())
+            .map(|kanji_info| kanji_info.onyomi().to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn kunyomi(&self) -> Vec<String> {
+        KANJI_DICTIONARY
+            .get_kanji_info(self.kanji.text())
+            .map(|kanji_info| kanji_info.kunyomi().to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn nanori(&self) -> Vec<String> {
+        KANJI_DICTIONARY
+            .get_kanji_info(self.kanji.text())
+            .map(|kanji_info| kanji_info.nanori().to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn stroke_count(&self) -> u8 {
+        KANJI_DICTIONARY
+            .get_kanji_info(self.kanji.text())
+            .map(|kanji_info| kanji_info.stroke_count())
+            .unwrap_or(0)
+    }
+
     pub fn radicals_info(&self) -> Result<Vec<&RadicalInfo>, OrigaError> {
         Ok(KANJI_DICTIONARY
             .get_kanji_info(self.kanji.text())?
             .radicals())
     }
+
+    pub fn stroke_order(&self) -> Result<KanjiStrokeOrder, OrigaError> {
+        STROKE_ORDER_DICTIONARY.get_stroke_order(self.kanji.text())
+    }
+}
+
+fn word_meaning(word: &str, native_language: &NativeLanguage) -> String {
+    VOCABULARY_DICTIONARY
+        .get_vocabulary_info(word)
+        .map(|vocabulary_info| vocabulary_info.translation(native_language).to_string())
+        .unwrap_or_default()
 }
 
 impl ExampleKanjiWord {
@@ -89,3 +152,43 @@ impl ExampleKanjiWord {
         &self.meaning
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meaning(word: &str) -> String {
+        format!("meaning of {}", word)
+    }
+
+    #[test]
+    fn should_rebuild_description_and_examples_when_kanji_changes() {
+        let mut card = KanjiCard::with_meanings("日".to_string(), meaning).unwrap();
+        let expected = KanjiCard::with_meanings("月".to_string(), meaning).unwrap();
+
+        card.edit_with_meanings(
+            Some(Question::new("月".to_string()).unwrap()),
+            None,
+            meaning,
+        )
+        .unwrap();
+
+        assert_eq!(card, expected);
+    }
+
+    #[test]
+    fn should_keep_examples_when_only_description_changes() {
+        let mut card = KanjiCard::with_meanings("日".to_string(), meaning).unwrap();
+        let example_words = card.example_words().to_vec();
+
+        card.edit_with_meanings(
+            Some(Question::new("日".to_string()).unwrap()),
+            Some(Answer::new("солнце".to_string()).unwrap()),
+            meaning,
+        )
+        .unwrap();
+
+        assert_eq!(card.description().text(), "солнце");
+        assert_eq!(card.example_words(), example_words);
+    }
+}
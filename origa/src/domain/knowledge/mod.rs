@@ -1,32 +1,45 @@
 mod card;
+mod cloze;
 mod daily_history;
 mod grammar;
 mod kanji;
+mod sentence;
 mod vocabulary;
 
-pub use card::{Card, StudyCard};
+pub use card::{Card, LessonCard, StudyCard};
+pub use cloze::ClozeCard;
 pub use daily_history::DailyHistoryItem;
 pub use grammar::GrammarRuleCard;
 pub use kanji::{ExampleKanjiWord, KanjiCard};
+pub use sentence::SentenceCard;
 pub use vocabulary::{ExamplePhrase, VocabularyCard};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::domain::{
-    OrigaError, Rating, ReviewLog, memory::MemoryState, value_objects::NativeLanguage,
+    CardMix, CardVariant, LessonOrder, OrigaError, QuizBuilder, ReviewLog, StudyPlan,
+    memory::MemoryState,
+    value_objects::{Answer, NativeLanguage, Question},
 };
 use chrono::{Duration, Utc};
 use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
-const NEW_CARDS_LIMIT: usize = 7;
-const HARD_CARDS_LIMIT: usize = 15;
-
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KnowledgeSet {
     study_cards: HashMap<Ulid, StudyCard>,
     lesson_history: Vec<DailyHistoryItem>,
+    #[serde(default)]
+    undo_state: Option<UndoState>,
+}
+
+/// Статистика дня до последней оценки, нужна для ее отмены
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct UndoState {
+    card_id: Ulid,
+    lesson_history_len: usize,
+    last_history_item: Option<DailyHistoryItem>,
 }
 
 impl Default for KnowledgeSet {
@@ -40,6 +53,7 @@ impl KnowledgeSet {
         Self {
             study_cards: HashMap::new(),
             lesson_history: Vec::new(),
+            undo_state: None,
         }
     }
 
@@ -63,10 +77,16 @@ impl KnowledgeSet {
     }
 
     pub fn create_card(&mut self, card: Card) -> Result<StudyCard, OrigaError> {
+        if let Card::Cloze(_) = card {
+            return Err(OrigaError::InvalidValues {
+                reason: "Cloze cards are generated from vocabulary cards".to_string(),
+            });
+        }
+
         let study_card = StudyCard::new(card);
         let card_id = *study_card.card_id();
 
-        self.validate_unique_card(study_card.card())?;
+        self.validate_unique_card(study_card.card(), None)?;
 
         if self
             .study_cards
@@ -81,8 +101,34 @@ impl KnowledgeSet {
         Ok(study_card)
     }
 
-    fn validate_unique_card(&self, card: &Card) -> Result<(), OrigaError> {
-        if self.study_cards.values().any(|c| match (card, c.card()) {
+    pub fn edit_card(
+        &mut self,
+        card_id: Ulid,
+        question: Option<Question>,
+        answer: Option<Answer>,
+        example_phrases: Option<Vec<ExamplePhrase>>,
+        native_language: &NativeLanguage,
+    ) -> Result<StudyCard, OrigaError> {
+        let card = self
+            .get_card(card_id)
+            .ok_or(OrigaError::CardNotFound { card_id })?
+            .card()
+            .edited(question, answer, example_phrases, native_language)?;
+
+        self.validate_unique_card(&card, Some(card_id))?;
+
+        let study_card = self.card_mut(card_id)?;
+        study_card.set_card(card);
+        Ok(study_card.clone())
+    }
+
+    fn validate_unique_card(&self, card: &Card, except: Option<Ulid>) -> Result<(), OrigaError> {
+        let mut other_cards = self
+            .study_cards
+            .values()
+            .filter(|c| Some(*c.card_id()) != except);
+
+        if other_cards.any(|c| match (card, c.card()) {
             (Card::Vocabulary(vocabulary_card), Card::Vocabulary(existing_vocabulary_card)) => {
                 vocabulary_card.word() == existing_vocabulary_card.word()
             }
@@ -92,6 +138,9 @@ impl KnowledgeSet {
             (Card::Grammar(grammar_rule_card), Card::Grammar(existing_grammar_rule_card)) => {
                 grammar_rule_card.title() == existing_grammar_rule_card.title()
             }
+            (Card::Sentence(sentence_card), Card::Sentence(existing_sentence_card)) => {
+                sentence_card.sentence() == existing_sentence_card.sentence()
+            }
             _ => false,
         }) {
             return Err(OrigaError::DuplicateCard {
@@ -102,50 +151,101 @@ impl KnowledgeSet {
         Ok(())
     }
 
-    pub fn cards_to_fixation(&self) -> HashMap<Ulid, Card> {
+    pub fn cards_to_fixation(&self, plan: &StudyPlan) -> Vec<LessonCard> {
         let mut cards = self
             .study_cards
-            .iter()
-            .filter(|(_, card)| card.memory().is_high_difficulty())
+            .values()
+            .filter(|card| !card.is_suspended() && card.memory().is_high_difficulty())
             .collect::<Vec<_>>();
 
-        cards.sort_by_key(|(_, card)| card.memory().next_review_date());
+        cards.sort_by_key(|card| card.memory().next_review_date());
         cards.reverse();
 
-        cards.truncate(HARD_CARDS_LIMIT);
+        let mut cards = plan
+            .card_mix()
+            .take(cards, plan.fixation_cards_limit(), |card| card.card());
+        Self::arrange(&mut cards, plan.order());
 
         cards
             .iter()
-            .map(|(card_id, card)| (**card_id, card.card().clone()))
+            .map(|card| {
+                LessonCard::new(*card.card_id(), card.card().clone(), CardVariant::Original)
+            })
             .collect()
     }
 
-    pub fn cards_to_lesson(&self, lang: &NativeLanguage) -> HashMap<Ulid, Card> {
-        let mut all_cards = self.study_cards.iter().collect::<Vec<_>>();
-        all_cards.sort_by_key(|(_, card)| card.memory().next_review_date());
+    pub fn cards_to_lesson(&self, lang: &NativeLanguage, plan: &StudyPlan) -> Vec<LessonCard> {
+        let mut all_cards = self
+            .study_cards
+            .values()
+            .filter(|card| !card.is_suspended())
+            .collect::<Vec<_>>();
+        all_cards.sort_by_key(|card| card.memory().next_review_date());
 
-        let mut priority_cards: Vec<_> = all_cards
+        let priority_cards: Vec<_> = all_cards
             .iter()
-            .filter(|(_, card)| card.memory().is_due() && card.memory().is_high_difficulty())
+            .filter(|card| card.memory().is_due() && card.memory().is_high_difficulty())
+            .copied()
             .collect();
 
-        if priority_cards.len() < NEW_CARDS_LIMIT {
-            let allowed_new = NEW_CARDS_LIMIT.saturating_sub(priority_cards.len());
-            let new_cards = all_cards
-                .iter()
-                .filter(|(_, card)| card.memory().is_new())
-                .take(allowed_new);
-
-            priority_cards.extend(new_cards);
-        }
+        let allowed_new = plan
+            .new_cards_per_day()
+            .saturating_sub(self.new_cards_studied_today())
+            .saturating_sub(priority_cards.len());
 
-        let known_cards = all_cards.iter().filter(|(_, card)| {
+        let known_cards = all_cards.iter().filter(|card| {
             card.memory().is_due()
                 && (card.memory().is_in_progress() || card.memory().is_known_card())
         });
 
-        priority_cards.extend(known_cards);
-        priority_cards.shuffle(&mut rand::rng());
+        let review_cards: Vec<_> = priority_cards
+            .into_iter()
+            .chain(known_cards.copied())
+            .collect();
+        let new_cards: Vec<_> = all_cards
+            .iter()
+            .filter(|card| card.memory().is_new() && plan.card_mix().includes(card.card()))
+            .copied()
+            .collect();
+
+        let (mut lesson_cards, mut new_cards) = if plan.bury_siblings() {
+            let mut seen_kanji = HashSet::new();
+            (
+                Self::take_burying_siblings(
+                    plan.card_mix(),
+                    review_cards,
+                    plan.max_reviews_per_lesson(),
+                    &mut seen_kanji,
+                ),
+                Self::take_burying_siblings(
+                    plan.card_mix(),
+                    new_cards,
+                    allowed_new,
+                    &mut seen_kanji,
+                ),
+            )
+        } else {
+            (
+                plan.card_mix()
+                    .take(review_cards, plan.max_reviews_per_lesson(), |card| {
+                        card.card()
+                    }),
+                plan.card_mix()
+                    .take(new_cards, allowed_new, |card| card.card()),
+            )
+        };
+
+        match plan.order() {
+            LessonOrder::Shuffled => {
+                lesson_cards.extend(new_cards);
+                lesson_cards.shuffle(&mut rand::rng());
+            }
+            order => {
+                Self::arrange(&mut lesson_cards, order);
+                new_cards.shuffle(&mut rand::rng());
+                lesson_cards.extend(new_cards);
+            }
+        }
 
         let known_rules: Vec<_> = self
             .study_cards
@@ -156,26 +256,147 @@ impl KnowledgeSet {
             })
             .collect();
 
-        priority_cards
+        let mut lesson_cards: Vec<_> = lesson_cards
             .iter()
-            .filter_map(|(card_id, card)| {
-                card.shuffle_card(lang, &known_rules)
-                    .ok()
-                    .map(|c| (**card_id, c))
+            .filter_map(|card| card.shuffle_card(lang, &known_rules).ok())
+            .collect();
+
+        if plan.multiple_choice() {
+            let quiz_builder = QuizBuilder::new(self, lang);
+            for lesson_card in lesson_cards.iter_mut() {
+                let quiz = match (lesson_card.card(), lesson_card.quiz_kind()) {
+                    (Card::Vocabulary(vocabulary), Some(kind)) => {
+                        quiz_builder.build(vocabulary, kind).ok()
+                    }
+                    _ => None,
+                };
+                lesson_card.set_quiz(quiz);
+            }
+        }
+
+        lesson_cards
+    }
+
+    /// Набирает карточки урока, откладывая до следующего урока те, чьи кандзи
+    /// уже встречались в выбранных карточках: иначе одна из них подсказывает
+    /// ответ на другую. Места отложенных карточек занимают следующие по очереди
+    fn take_burying_siblings<'a>(
+        card_mix: &CardMix,
+        mut cards: Vec<&'a StudyCard>,
+        limit: usize,
+        seen_kanji: &mut HashSet<char>,
+    ) -> Vec<&'a StudyCard> {
+        loop {
+            let taken = card_mix.take(cards.clone(), limit, |card| card.card());
+
+            let mut seen = seen_kanji.clone();
+            let buried: HashSet<Ulid> = taken
+                .iter()
+                .filter(|card| {
+                    let kanji = card.card().related_kanji();
+                    if kanji.iter().any(|c| seen.contains(c)) {
+                        return true;
+                    }
+                    seen.extend(kanji);
+                    false
+                })
+                .map(|card| *card.card_id())
+                .collect();
+
+            if buried.is_empty() {
+                *seen_kanji = seen;
+                return taken;
+            }
+            cards.retain(|card| !buried.contains(card.card_id()));
+        }
+    }
+
+    fn new_cards_studied_today(&self) -> usize {
+        let today = Utc::now().date_naive();
+        self.study_cards
+            .values()
+            .filter(|card| {
+                card.memory()
+                    .reviews()
+                    .front()
+                    .is_some_and(|review| review.timestamp().date_naive() == today)
+            })
+            .count()
+    }
+
+    fn arrange(cards: &mut [&StudyCard], order: &LessonOrder) {
+        match order {
+            LessonOrder::Shuffled => cards.shuffle(&mut rand::rng()),
+            LessonOrder::DueDate => cards.sort_by_key(|card| card.memory().next_review_date()),
+            LessonOrder::Difficulty => cards.sort_by(|a, b| {
+                let difficulty = |card: &StudyCard| {
+                    card.memory()
+                        .difficulty()
+                        .map(|difficulty| difficulty.value())
+                        .unwrap_or(0.0)
+                };
+                difficulty(b).total_cmp(&difficulty(a))
+            }),
+        }
+    }
+
+    /// Кандзи, карточки которых изучены до стабильного уровня
+    pub fn known_kanji(&self) -> HashSet<char> {
+        self.study_cards
+            .values()
+            .filter(|card| card.memory().is_known_card())
+            .filter_map(|card| match card.card() {
+                Card::Kanji(kanji) => kanji.kanji().text().chars().next(),
+                _ => None,
             })
             .collect()
     }
 
+    pub fn leeches(&self) -> Vec<&StudyCard> {
+        self.study_cards
+            .values()
+            .filter(|card| card.memory().is_leech())
+            .collect()
+    }
+
+    pub fn suspend_card(&mut self, card_id: Ulid) -> Result<(), OrigaError> {
+        self.card_mut(card_id)?.set_suspended(true);
+        Ok(())
+    }
+
+    pub fn unsuspend_card(&mut self, card_id: Ulid) -> Result<(), OrigaError> {
+        self.card_mut(card_id)?.set_suspended(false);
+        Ok(())
+    }
+
+    pub fn reset_card(&mut self, card_id: Ulid) -> Result<(), OrigaError> {
+        self.card_mut(card_id)?.reset();
+        self.update_history();
+        Ok(())
+    }
+
+    fn card_mut(&mut self, card_id: Ulid) -> Result<&mut StudyCard, OrigaError> {
+        self.study_cards
+            .get_mut(&card_id)
+            .ok_or(OrigaError::CardNotFound { card_id })
+    }
+
     pub(crate) fn rate_card(
         &mut self,
         card_id: Ulid,
-        rating: Rating,
-        interval: Duration,
         memory_state: MemoryState,
+        review: ReviewLog,
     ) -> Result<(), OrigaError> {
         if let Some(card) = self.study_cards.get_mut(&card_id) {
-            let review = ReviewLog::new(rating, interval);
             card.add_review(memory_state, review);
+            if card.memory().is_leech_threshold_reached() {
+                card.set_suspended(true);
+            }
+            self.undo_state = Some(UndoState {
+                card_id,
+                lesson_history_len: self.lesson_history.len(),
+                last_history_item: self.lesson_history.last().cloned(),
+            });
             self.update_history();
             Ok(())
         } else {
@@ -183,6 +404,35 @@ impl KnowledgeSet {
         }
     }
 
+    /// Отменяет самую последнюю оценку. Отменить можно только одну оценку:
+    /// более ранние оценки других карточек остаются
+    pub(crate) fn undo_last_rating(&mut self) -> Result<Ulid, OrigaError> {
+        let undo_state = self.undo_state.take().ok_or(OrigaError::InvalidValues {
+            reason: "There are no ratings to undo".to_string(),
+        })?;
+        let card_id = undo_state.card_id;
+
+        let card = self.card_mut(card_id)?;
+        let was_suspended_by_review = card.memory().is_leech_threshold_reached();
+        card.undo_last_review().ok_or(OrigaError::InvalidValues {
+            reason: "The last rating has already been undone".to_string(),
+        })?;
+
+        if was_suspended_by_review {
+            card.set_suspended(false);
+        }
+
+        self.lesson_history.truncate(undo_state.lesson_history_len);
+        if let (Some(item), Some(previous)) = (
+            self.lesson_history.last_mut(),
+            undo_state.last_history_item.as_ref(),
+        ) {
+            item.restore_counters(previous);
+        }
+
+        Ok(card_id)
+    }
+
     pub(crate) fn add_lesson_duration(&mut self, lesson_duration: Duration) {
         if let Some(last_item) = self.lesson_history.last_mut() {
             last_item.add_lesson_duration(lesson_duration);
@@ -244,3 +494,214 @@ impl KnowledgeSet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Difficulty, LearningState, Rating, ReviewMode, Stability};
+
+    fn kanji_card(kanji: &str) -> Card {
+        serde_json::from_str(&format!(
+            r#"{{"Kanji": {{"kanji": {{"text": "{}"}}, "description": {{"text": "-"}},
+                "example_words": []}}}}"#,
+            kanji
+        ))
+        .unwrap()
+    }
+
+    fn vocabulary_card(word: &str) -> Card {
+        serde_json::from_str(&format!(
+            r#"{{"Vocabulary": {{"word": {{"text": "{}"}}, "meaning": {{"text": "-"}},
+                "example_phrases": []}}}}"#,
+            word
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_fill_places_of_buried_siblings() {
+        let mut knowledge_set = KnowledgeSet::new();
+        let cards: Vec<_> = ["日本", "日曜", "月", "火"]
+            .into_iter()
+            .map(|word| knowledge_set.create_card(vocabulary_card(word)).unwrap())
+            .collect();
+        let mut seen_kanji = HashSet::new();
+
+        let taken = KnowledgeSet::take_burying_siblings(
+            &CardMix::default(),
+            cards.iter().collect(),
+            3,
+            &mut seen_kanji,
+        );
+
+        let words: Vec<_> = taken
+            .iter()
+            .map(|card| card.card().question().text())
+            .collect();
+        assert_eq!(words, vec!["日本", "月", "火"]);
+        assert!(seen_kanji.contains(&'本'));
+    }
+
+    #[test]
+    fn should_show_original_card_for_stale_cloze_phrase() {
+        let card = StudyCard::new(vocabulary_card("日"));
+
+        let lesson_card = card
+            .lesson_card(
+                CardVariant::Cloze { phrase_index: 2 },
+                &NativeLanguage::Russian,
+            )
+            .unwrap();
+
+        assert_eq!(lesson_card.variant(), CardVariant::Original);
+        assert_eq!(lesson_card.card(), card.card());
+    }
+
+    fn relearning_state(lapses: u32) -> MemoryState {
+        MemoryState::new(
+            Stability::new(1.0).unwrap(),
+            Difficulty::new(5.0).unwrap(),
+            Utc::now(),
+            LearningState::Relearning,
+            lapses + 1,
+            lapses,
+        )
+    }
+
+    fn good() -> ReviewLog {
+        ReviewLog::new(
+            Rating::Good,
+            Duration::zero(),
+            ReviewMode::Standard,
+            CardVariant::Original,
+            None,
+        )
+    }
+
+    fn again() -> ReviewLog {
+        ReviewLog::new(
+            Rating::Again,
+            Duration::zero(),
+            ReviewMode::Standard,
+            CardVariant::Original,
+            None,
+        )
+    }
+
+    #[test]
+    fn should_suspend_leech_and_unsuspend_on_undo() {
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(kanji_card("日"))
+            .unwrap()
+            .card_id();
+
+        for lapses in 0..8 {
+            knowledge_set
+                .rate_card(card_id, relearning_state(lapses), good())
+                .unwrap();
+            assert!(!knowledge_set.get_card(card_id).unwrap().is_suspended());
+            knowledge_set
+                .rate_card(card_id, relearning_state(lapses + 1), again())
+                .unwrap();
+        }
+        assert!(knowledge_set.get_card(card_id).unwrap().is_suspended());
+        assert_eq!(knowledge_set.leeches().len(), 1);
+
+        knowledge_set.undo_last_rating().unwrap();
+        assert!(!knowledge_set.get_card(card_id).unwrap().is_suspended());
+    }
+
+    #[test]
+    fn should_restore_card_and_lesson_history_on_undo() {
+        let mut knowledge_set = KnowledgeSet::new();
+        let card_id = *knowledge_set
+            .create_card(kanji_card("日"))
+            .unwrap()
+            .card_id();
+        knowledge_set
+            .rate_card(card_id, relearning_state(0), again())
+            .unwrap();
+        knowledge_set.add_lesson_duration(Duration::minutes(5));
+        let before = knowledge_set.clone();
+
+        knowledge_set
+            .rate_card(card_id, relearning_state(1), again())
+            .unwrap();
+        let json = serde_json::to_string(&knowledge_set).unwrap();
+        let mut knowledge_set: KnowledgeSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(knowledge_set.undo_last_rating().unwrap(), card_id);
+
+        assert_eq!(
+            knowledge_set.get_card(card_id).unwrap().memory().reviews(),
+            before.get_card(card_id).unwrap().memory().reviews()
+        );
+        assert_eq!(knowledge_set.lesson_history(), before.lesson_history());
+        assert!(knowledge_set.undo_last_rating().is_err());
+    }
+
+    #[test]
+    fn should_undo_only_the_latest_rating() {
+        let mut knowledge_set = KnowledgeSet::new();
+        let first_id = *knowledge_set
+            .create_card(kanji_card("日"))
+            .unwrap()
+            .card_id();
+        let second_id = *knowledge_set
+            .create_card(kanji_card("月"))
+            .unwrap()
+            .card_id();
+
+        knowledge_set
+            .rate_card(first_id, relearning_state(0), again())
+            .unwrap();
+        knowledge_set
+            .rate_card(second_id, relearning_state(0), again())
+            .unwrap();
+
+        assert_eq!(knowledge_set.undo_last_rating().unwrap(), second_id);
+        assert!(knowledge_set.undo_last_rating().is_err());
+        assert_eq!(
+            knowledge_set
+                .get_card(first_id)
+                .unwrap()
+                .memory()
+                .reviews()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn should_keep_due_reviews_of_types_without_share() {
+        let mut knowledge_set = KnowledgeSet::new();
+        let due_id = *knowledge_set
+            .create_card(kanji_card("日"))
+            .unwrap()
+            .card_id();
+        knowledge_set.create_card(kanji_card("月")).unwrap();
+        knowledge_set.create_card(vocabulary_card("猫")).unwrap();
+        knowledge_set
+            .rate_card(due_id, relearning_state(0), again())
+            .unwrap();
+        let plan = StudyPlan::new(
+            7,
+            100,
+            15,
+            CardMix::new(1, 0, 0).unwrap(),
+            LessonOrder::DueDate,
+            false,
+            false,
+        )
+        .unwrap();
+
+        let mut words: Vec<_> = knowledge_set
+            .cards_to_lesson(&NativeLanguage::Russian, &plan)
+            .iter()
+            .map(|card| card.card().question().text().to_string())
+            .collect();
+        words.sort();
+
+        assert_eq!(words, vec!["日", "猫"]);
+    }
+}
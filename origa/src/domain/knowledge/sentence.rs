@@ -0,0 +1,141 @@
+use crate::domain::{
+    OrigaError,
+    grammar::GRAMMAR_RULES,
+    tokenizer::tokenize_text,
+    value_objects::{Answer, Question},
+};
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentenceCard {
+    sentence: Question,
+    translation: Answer,
+    words: Vec<String>,
+    grammar_rules: Vec<Ulid>,
+}
+
+impl SentenceCard {
+    pub fn new(sentence: Question, translation: Answer) -> Result<Self, OrigaError> {
+        let (words, grammar_rules) = Self::find_links(sentence.text())?;
+
+        Ok(Self {
+            sentence,
+            translation,
+            words,
+            grammar_rules,
+        })
+    }
+
+    pub fn sentence(&self) -> &Question {
+        &self.sentence
+    }
+
+    pub fn translation(&self) -> &Answer {
+        &self.translation
+    }
+
+    /// Словарные формы слов, из которых состоит предложение
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Грамматические правила, которые встречаются в предложении
+    pub fn grammar_rules(&self) -> &[Ulid] {
+        &self.grammar_rules
+    }
+
+    pub(crate) fn edit(
+        &mut self,
+        sentence: Option<Question>,
+        translation: Option<Answer>,
+    ) -> Result<(), OrigaError> {
+        if let Some(sentence) = sentence {
+            let (words, grammar_rules) = Self::find_links(sentence.text())?;
+            self.sentence = sentence;
+            self.words = words;
+            self.grammar_rules = grammar_rules;
+        }
+        if let Some(translation) = translation {
+            self.translation = translation;
+        }
+        Ok(())
+    }
+
+    /// Правило считается найденным, если его форма, построенная от
+    /// словарной формы одного из слов, встречается в тексте предложения
+    fn find_links(sentence: &str) -> Result<(Vec<String>, Vec<Ulid>), OrigaError> {
+        let tokens = tokenize_text(sentence)?;
+
+        let mut words = Vec::new();
+        for token in tokens
+            .iter()
+            .filter(|token| token.part_of_speech().is_vocabulary_word())
+        {
+            let word = token.orthographic_base_form().to_string();
+            if !words.contains(&word) {
+                words.push(word);
+            }
+        }
+
+        let grammar_rules = GRAMMAR_RULES
+            .iter()
+            .filter(|rule| {
+                tokens.iter().any(|token| {
+                    rule.info().apply_to().contains(token.part_of_speech())
+                        && rule
+                            .format(token.orthographic_base_form(), token.part_of_speech())
+                            .is_ok_and(|formatted| sentence.contains(&formatted))
+                })
+            })
+            .map(|rule| *rule.info().rule_id())
+            .collect();
+
+        Ok((words, grammar_rules))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentence_card(sentence: &str) -> SentenceCard {
+        SentenceCard::new(
+            Question::new(sentence.to_string()).unwrap(),
+            Answer::new("-".to_string()).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_relink_dictionary_forms_of_sentence_words() {
+        let mut card = sentence_card("猫が魚を食べました");
+
+        assert!(card.words().contains(&"猫".to_string()));
+        assert!(card.words().contains(&"魚".to_string()));
+        assert!(card.words().contains(&"食べる".to_string()));
+        assert!(!card.words().contains(&"が".to_string()));
+
+        card.edit(Some(Question::new("犬が走る".to_string()).unwrap()), None)
+            .unwrap();
+        assert!(card.words().contains(&"犬".to_string()));
+        assert!(!card.words().contains(&"猫".to_string()));
+    }
+
+    #[test]
+    fn should_keep_links_when_only_translation_changes() {
+        let mut card: SentenceCard = serde_json::from_str(
+            r#"{"sentence": {"text": "猫が魚を食べました"}, "translation": {"text": "-"},
+                "words": ["猫", "魚", "食べる"], "grammar_rules": []}"#,
+        )
+        .unwrap();
+
+        card.edit(
+            None,
+            Some(Answer::new("Кошка съела рыбу".to_string()).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(card.translation().text(), "Кошка съела рыбу");
+        assert_eq!(card.words(), ["猫", "魚", "食べる"]);
+    }
+}
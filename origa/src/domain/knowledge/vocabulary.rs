@@ -1,7 +1,9 @@
 use crate::domain::OrigaError;
-use crate::domain::dictionary::{KANJI_DICTIONARY, KanjiInfo};
+use crate::domain::dictionary::{KANJI_DICTIONARY, KanjiInfo, VOCABULARY_DICTIONARY};
 use crate::domain::grammar::GrammarRule;
 use crate::domain::japanese::JapaneseChar;
+use crate::domain::knowledge::ClozeCard;
+use crate::domain::pitch_accent::PitchAccent;
 use crate::domain::tokenizer::{PartOfSpeech, tokenize_text};
 use crate::domain::{Answer, JapaneseLevel, NativeLanguage, Question};
 use serde::{Deserialize, Serialize};
@@ -34,6 +36,14 @@ impl VocabularyCard {
         &self.example_phrases
     }
 
+    /// Ударения слова из словаря
+    pub fn pitch_accents(&self) -> Vec<PitchAccent> {
+        VOCABULARY_DICTIONARY
+            .get_vocabulary_info(self.word.text())
+            .map(|info| info.pitch_accents().to_vec())
+            .unwrap_or_default()
+    }
+
     pub fn get_kanji_cards(&self, current_level: &JapaneseLevel) -> Vec<&KanjiInfo> {
         self.word
             .text()
@@ -79,6 +89,41 @@ impl VocabularyCard {
         })
     }
 
+    /// Карточка с пропуском слова в примере фразы с номером `phrase_index`
+    pub fn cloze(&self, phrase_index: usize) -> Result<ClozeCard, OrigaError> {
+        let phrase = self
+            .example_phrases
+            .get(phrase_index)
+            .ok_or(OrigaError::InvalidValues {
+                reason: format!("Example phrase {} not found", phrase_index),
+            })?;
+        ClozeCard::new(self.word.text(), phrase)
+    }
+
+    /// Номера примеров фраз, в которых удалось найти слово
+    pub(crate) fn cloze_phrase_indexes(&self) -> Vec<usize> {
+        (0..self.example_phrases.len())
+            .filter(|index| self.cloze(*index).is_ok())
+            .collect()
+    }
+
+    pub(crate) fn edit(
+        &mut self,
+        word: Option<Question>,
+        meaning: Option<Answer>,
+        example_phrases: Option<Vec<ExamplePhrase>>,
+    ) {
+        if let Some(word) = word {
+            self.word = word;
+        }
+        if let Some(meaning) = meaning {
+            self.meaning = meaning;
+        }
+        if let Some(example_phrases) = example_phrases {
+            self.example_phrases = example_phrases;
+        }
+    }
+
     pub fn revert(&self) -> Result<Self, OrigaError> {
         Ok(Self {
             word: Question::new(self.meaning.text().to_string())?,
@@ -1,6 +1,8 @@
 mod value;
 
-pub use value::{Difficulty, MemoryState, Rating, ReviewLog, Stability};
+pub use value::{
+    CardVariant, Difficulty, LearningState, MemoryState, Rating, ReviewLog, ReviewMode, Stability,
+};
 
 use std::collections::VecDeque;
 
@@ -10,11 +12,74 @@ use serde::{Deserialize, Serialize};
 const KNOWN_CARD_STABILITY_THRESHOLD: f64 = 10.0;
 const HIGH_DIFFICULTY_THRESHOLD: f64 = 5.0;
 const MAX_DAYS_INTERVAL_THRESHOLD: i64 = 10;
+const LEECH_LAPSES_THRESHOLD: u32 = 8;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(from = "MemoryHistoryStoredType")]
 pub struct MemoryHistory {
     current_state: Option<MemoryState>,
     reviews: VecDeque<ReviewLog>,
+    #[serde(default)]
+    undo_state: Option<UndoState>,
+}
+
+#[derive(Deserialize)]
+struct MemoryHistoryStoredType {
+    current_state: Option<MemoryState>,
+    reviews: VecDeque<ReviewLog>,
+    #[serde(default)]
+    undo_state: Option<UndoState>,
+}
+
+impl From<MemoryHistoryStoredType> for MemoryHistory {
+    fn from(stored: MemoryHistoryStoredType) -> Self {
+        let mut current_state = stored.current_state;
+        if let Some(state) = current_state.as_mut() {
+            state.backfill_counters(
+                stored.reviews.len() as u32,
+                lapses_from_reviews(&stored.reviews),
+            );
+        }
+
+        Self {
+            current_state,
+            reviews: stored.reviews,
+            undo_state: stored.undo_state,
+        }
+    }
+}
+
+/// Забывания по журналу так, как их считает FSRS: оценка Again после успешного
+/// ответа, серия Again подряд считается одним забыванием
+fn lapses_from_reviews<'a>(reviews: impl IntoIterator<Item = &'a ReviewLog>) -> u32 {
+    let mut recalled = false;
+    let mut lapses = 0;
+
+    for review in reviews {
+        if review.rating() == Rating::Again {
+            lapses += recalled as u32;
+            recalled = false;
+        } else {
+            recalled = true;
+        }
+    }
+
+    lapses
+}
+
+/// Сколько раз счетчик забываний прошел порог пиявки и каждую следующую
+/// половину порога после него
+fn leech_milestones(lapses: u32) -> u32 {
+    match lapses.checked_sub(LEECH_LAPSES_THRESHOLD) {
+        Some(over) => 1 + over / (LEECH_LAPSES_THRESHOLD / 2),
+        None => 0,
+    }
+}
+
+/// Состояние памяти до последнего повторения, нужно для его отмены
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+struct UndoState {
+    memory_state: Option<MemoryState>,
 }
 
 impl Default for MemoryHistory {
@@ -28,6 +93,7 @@ impl MemoryHistory {
         Self {
             current_state: None,
             reviews: VecDeque::new(),
+            undo_state: None,
         }
     }
 
@@ -56,15 +122,81 @@ impl MemoryHistory {
             .map(|state| state.next_review_date())
     }
 
+    pub fn learning_state(&self) -> LearningState {
+        self.current_state
+            .as_ref()
+            .map(|state| state.learning_state())
+            .unwrap_or(LearningState::New)
+    }
+
+    pub fn reps(&self) -> u32 {
+        self.current_state
+            .as_ref()
+            .map(|state| state.reps())
+            .unwrap_or(0)
+    }
+
+    pub fn lapses(&self) -> u32 {
+        self.current_state
+            .as_ref()
+            .map(|state| state.lapses())
+            .unwrap_or(0)
+    }
+
     pub fn reviews(&self) -> &VecDeque<ReviewLog> {
         &self.reviews
     }
 
     pub(crate) fn add_review(&mut self, memory_state: MemoryState, review: ReviewLog) {
-        self.current_state = Some(memory_state);
+        self.undo_state = Some(UndoState {
+            memory_state: self.current_state.replace(memory_state),
+        });
         self.reviews.push_back(review);
     }
 
+    /// Последнее повторение можно отменить
+    pub fn can_undo(&self) -> bool {
+        self.undo_state.is_some() && !self.reviews.is_empty()
+    }
+
+    /// Отменяет последнее повторение и возвращает удаленную запись журнала
+    pub(crate) fn undo_last_review(&mut self) -> Option<ReviewLog> {
+        if !self.can_undo() {
+            return None;
+        }
+
+        let undo_state = self.undo_state.take()?;
+        self.current_state = undo_state.memory_state;
+        self.reviews.pop_back()
+    }
+
+    /// Карта которую пользователь раз за разом забывает
+    pub fn is_leech(&self) -> bool {
+        self.leech_lapses(self.reviews.len()) >= LEECH_LAPSES_THRESHOLD
+    }
+
+    /// Последнее повторение вне закрепления перевело счетчик забываний через
+    /// порог пиявки или очередную половину порога после него
+    pub(crate) fn is_leech_threshold_reached(&self) -> bool {
+        if self.reviews.back().map(|review| review.mode()) != Some(ReviewMode::Standard) {
+            return false;
+        }
+
+        let previous_lapses = self.leech_lapses(self.reviews.len() - 1);
+        leech_milestones(self.leech_lapses(self.reviews.len())) > leech_milestones(previous_lapses)
+    }
+
+    /// Забывания в первых `count` повторениях без учета закрепления: ошибки
+    /// в закреплении не должны сдвигать порог пиявки
+    fn leech_lapses(&self, count: usize) -> u32 {
+        lapses_from_reviews(
+            self.reviews
+                .iter()
+                .take(count)
+                .filter(|review| review.mode() == ReviewMode::Standard),
+        )
+    }
+
     pub fn last_review_date(&self) -> Option<DateTime<Utc>> {
         self.reviews.back().map(|review| review.timestamp())
     }
@@ -88,16 +220,129 @@ impl MemoryHistory {
                 .unwrap_or(false)
     }
 
+    /// Карта которая проходит первичные короткие шаги изучения
+    pub fn is_learning(&self) -> bool {
+        self.learning_state() == LearningState::Learning
+    }
+
+    /// Карта которая была забыта и заново проходит короткие шаги изучения
+    pub fn is_relearning(&self) -> bool {
+        self.learning_state() == LearningState::Relearning
+    }
+
     /// Карта которая уже изучена до стабильного уровня
     pub fn is_known_card(&self) -> bool {
-        self.stability()
-            .map(|stability| stability.value() > KNOWN_CARD_STABILITY_THRESHOLD)
-            .unwrap_or(false)
+        self.learning_state() == LearningState::Review
+            && self
+                .stability()
+                .map(|stability| stability.value() > KNOWN_CARD_STABILITY_THRESHOLD)
+                .unwrap_or(false)
             && !self.is_high_difficulty()
     }
 
     /// Карта которая еще не была изучена до стабильного уровня, но уже начала изучаться
     pub fn is_in_progress(&self) -> bool {
-        !self.is_known_card() && !self.is_high_difficulty() && !self.is_new()
+        match self.learning_state() {
+            LearningState::New => false,
+            LearningState::Learning | LearningState::Relearning => !self.is_high_difficulty(),
+            LearningState::Review => !self.is_known_card() && !self.is_high_difficulty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_HISTORY: &str = r#"{
+        "current_state": {
+            "stability": {"value": 12.5},
+            "difficulty": {"value": 4.2},
+            "next_review_date": "2025-01-20T10:00:00Z"
+        },
+        "reviews": [
+            {"id": "01JH0000000000000000000001", "rating": "Good",
+             "timestamp": "2025-01-01T10:00:00Z", "interval": [86400, 0]},
+            {"id": "01JH0000000000000000000002", "rating": "Again",
+             "timestamp": "2025-01-02T10:00:00Z", "interval": [600, 0]},
+            {"id": "01JH0000000000000000000003", "rating": "Again",
+             "timestamp": "2025-01-02T10:10:00Z", "interval": [600, 0]},
+            {"id": "01JH0000000000000000000004", "rating": "Good",
+             "timestamp": "2025-01-03T10:00:00Z", "interval": [259200, 0]}
+        ]
+    }"#;
+
+    fn review_state(lapses: u32) -> MemoryState {
+        MemoryState::new(
+            Stability::new(1.0).unwrap(),
+            Difficulty::new(5.0).unwrap(),
+            Utc::now(),
+            LearningState::Relearning,
+            lapses + 1,
+            lapses,
+        )
+    }
+
+    fn review(rating: Rating, mode: ReviewMode) -> ReviewLog {
+        ReviewLog::new(rating, TimeDelta::zero(), mode, CardVariant::Original, None)
+    }
+
+    /// Успешный ответ и забывание в одном режиме
+    fn lapse(history: &mut MemoryHistory, mode: ReviewMode) {
+        let lapses = history.lapses();
+        history.add_review(review_state(lapses), review(Rating::Good, mode));
+        history.add_review(review_state(lapses + 1), review(Rating::Again, mode));
+    }
+
+    #[test]
+    fn should_reach_leech_threshold_only_when_review_adds_lapse() {
+        let mut history = MemoryHistory::new();
+        for _ in 0..7 {
+            lapse(&mut history, ReviewMode::Standard);
+        }
+        assert!(!history.is_leech());
+        assert!(!history.is_leech_threshold_reached());
+
+        lapse(&mut history, ReviewMode::Standard);
+        assert!(history.is_leech());
+        assert!(history.is_leech_threshold_reached());
+
+        // Повторное Again в шагах переизучения не добавляет забывания
+        history.add_review(review_state(8), review(Rating::Again, ReviewMode::Standard));
+        assert!(!history.is_leech_threshold_reached());
+
+        for _ in 0..3 {
+            lapse(&mut history, ReviewMode::Standard);
+        }
+        assert!(!history.is_leech_threshold_reached());
+        lapse(&mut history, ReviewMode::Standard);
+        assert!(history.is_leech_threshold_reached());
+    }
+
+    #[test]
+    fn should_count_leech_lapses_without_fixation() {
+        let mut history = MemoryHistory::new();
+        for _ in 0..7 {
+            lapse(&mut history, ReviewMode::Standard);
+        }
+        lapse(&mut history, ReviewMode::Fixation);
+        assert!(!history.is_leech_threshold_reached());
+        assert!(!history.is_leech());
+
+        lapse(&mut history, ReviewMode::Standard);
+        assert!(history.is_leech_threshold_reached());
+    }
+
+    #[test]
+    fn should_backfill_counters_of_legacy_history() {
+        let history: MemoryHistory = serde_json::from_str(LEGACY_HISTORY).unwrap();
+
+        assert_eq!(history.reps(), 4);
+        assert_eq!(history.lapses(), 1);
+        assert_eq!(history.learning_state(), LearningState::Review);
+
+        let json = serde_json::to_string(&history).unwrap();
+        let restored: MemoryHistory = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, history);
     }
 }
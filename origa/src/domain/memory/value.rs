@@ -10,6 +10,12 @@ pub struct MemoryState {
     stability: Stability,
     difficulty: Difficulty,
     next_review_date: DateTime<Utc>,
+    #[serde(default = "LearningState::legacy")]
+    learning_state: LearningState,
+    #[serde(default)]
+    reps: u32,
+    #[serde(default)]
+    lapses: u32,
 }
 
 impl MemoryState {
@@ -17,11 +23,17 @@ impl MemoryState {
         stability: Stability,
         difficulty: Difficulty,
         next_review_date: DateTime<Utc>,
+        learning_state: LearningState,
+        reps: u32,
+        lapses: u32,
     ) -> Self {
         Self {
             stability,
             difficulty,
             next_review_date,
+            learning_state,
+            reps,
+            lapses,
         }
     }
 
@@ -36,33 +48,84 @@ impl MemoryState {
     pub fn next_review_date(&self) -> &DateTime<Utc> {
         &self.next_review_date
     }
+
+    pub fn learning_state(&self) -> LearningState {
+        self.learning_state
+    }
+
+    pub fn reps(&self) -> u32 {
+        self.reps
+    }
+
+    pub fn lapses(&self) -> u32 {
+        self.lapses
+    }
+
+    /// Состояния, сохраненные до появления счетчиков, получают их из журнала повторений
+    pub(super) fn backfill_counters(&mut self, reps: u32, lapses: u32) {
+        if self.reps == 0 {
+            self.reps = reps;
+            self.lapses = lapses;
+        }
+    }
 }
 
 impl fmt::Display for MemoryState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Stability: {}, Difficulty: {}, Next review date: {}",
-            self.stability, self.difficulty, self.next_review_date
+            "State: {:?}, Stability: {}, Difficulty: {}, Next review date: {}",
+            self.learning_state, self.stability, self.difficulty, self.next_review_date
         )
     }
 }
 
+/// Этап изучения карты в терминах FSRS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub enum LearningState {
+    New,
+    Learning,
+    Review,
+    Relearning,
+}
+
+impl LearningState {
+    /// Состояния сохраненные до появления этапов всегда считались повторением
+    fn legacy() -> Self {
+        LearningState::Review
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct ReviewLog {
     id: Ulid,
     rating: Rating,
     timestamp: DateTime<Utc>,
     interval: Duration,
+    #[serde(default)]
+    mode: ReviewMode,
+    #[serde(default)]
+    variant: CardVariant,
+    #[serde(default)]
+    response_time: Option<Duration>,
 }
 
 impl ReviewLog {
-    pub fn new(rating: Rating, interval: Duration) -> Self {
+    pub fn new(
+        rating: Rating,
+        interval: Duration,
+        mode: ReviewMode,
+        variant: CardVariant,
+        response_time: Option<Duration>,
+    ) -> Self {
         Self {
             id: Ulid::new(),
             rating,
             timestamp: Utc::now(),
             interval,
+            mode,
+            variant,
+            response_time,
         }
     }
 
@@ -81,6 +144,44 @@ impl ReviewLog {
     pub fn interval(&self) -> Duration {
         self.interval
     }
+
+    pub fn mode(&self) -> ReviewMode {
+        self.mode
+    }
+
+    pub fn variant(&self) -> CardVariant {
+        self.variant
+    }
+
+    /// Время от показа карточки до оценки, если оно было измерено
+    pub fn response_time(&self) -> Option<Duration> {
+        self.response_time
+    }
+}
+
+/// Вид урока, в котором была выставлена оценка
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize, Default)]
+pub enum ReviewMode {
+    #[default]
+    Standard,
+    Fixation,
+}
+
+/// В каком виде карточка была показана пользователю
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize, Default)]
+pub enum CardVariant {
+    #[default]
+    Original,
+    Reversed,
+    GrammarRule {
+        rule_id: Ulid,
+    },
+    /// Слово скрыто в примере фразы с указанным номером
+    Cloze {
+        phrase_index: usize,
+    },
+    /// По значению нужно написать кандзи, ответом служит порядок черт
+    Writing,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
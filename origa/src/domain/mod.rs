@@ -1,32 +1,52 @@
+mod answer_check;
 mod dictionary;
 mod error;
 mod furigana;
 pub mod grammar;
+mod handwriting;
 mod japanese;
 mod knowledge;
 mod memory;
+mod pitch_accent;
+mod quiz;
+mod romaji;
 mod settings;
+mod text_analysis;
 mod tokenizer;
 mod user;
 mod value_objects;
 mod well_known_set;
 
+pub use answer_check::{AnswerCheck, CharDiff, check_answer};
 pub use dictionary::{
-    KANJI_DICTIONARY, KanjiInfo, PopularWord, RADICAL_DICTIONARY, RadicalInfo,
-    VOCABULARY_DICTIONARY, VocabularyInfo,
+    KANJI_DICTIONARY, KanjiInfo, KanjiStrokeOrder, PopularWord, RADICAL_DICTIONARY, RadicalInfo,
+    STROKE_ORDER_DICTIONARY, Stroke, StrokeOrderDatabase, StrokePoint, VOCABULARY_DICTIONARY,
+    VocabularyInfo, VocabularySense, import_jmdict, import_kanjidic2, import_pitch_accents,
 };
 pub use error::OrigaError;
-pub use furigana::furiganize_text;
+pub use furigana::{
+    FuriganaFormat, FuriganaSegment, furiganize_for_user, furiganize_text, render_furigana,
+};
 pub use grammar::{
     GRAMMAR_RULES, GrammarRule, GrammarRuleContent, GrammarRuleInfo, get_rule_by_id,
 };
+pub use handwriting::{
+    HANDWRITING_RECOGNIZER, HandwritingCandidate, HandwritingRecognizer, WritingCheck,
+};
 pub use japanese::{JapaneseChar, JapaneseText, filter_japanese_text};
 pub use knowledge::{
-    Card, DailyHistoryItem, ExampleKanjiWord, ExamplePhrase, GrammarRuleCard, KanjiCard,
-    KnowledgeSet, StudyCard, VocabularyCard,
+    Card, ClozeCard, DailyHistoryItem, ExampleKanjiWord, ExamplePhrase, GrammarRuleCard, KanjiCard,
+    KnowledgeSet, LessonCard, SentenceCard, StudyCard, VocabularyCard,
+};
+pub use memory::{
+    CardVariant, Difficulty, LearningState, MemoryHistory, MemoryState, Rating, ReviewLog,
+    ReviewMode, Stability,
 };
-pub use memory::{Difficulty, MemoryHistory, MemoryState, Rating, ReviewLog, Stability};
-pub use settings::{LlmSettings, UserSettings};
+pub use pitch_accent::{PitchAccent, PitchAccentPattern, PitchMora, split_morae};
+pub use quiz::{Quiz, QuizBuilder, QuizKind};
+pub use romaji::{is_romaji, kana_to_romaji, romaji_to_hiragana, romaji_to_katakana};
+pub use settings::{CardMix, LessonOrder, LlmSettings, SrsParameters, StudyPlan, UserSettings};
+pub use text_analysis::TextAnalysis;
 pub use tokenizer::{PartOfSpeech, TokenInfo, tokenize_text};
 pub use user::User;
 pub use value_objects::{Answer, JapaneseLevel, NativeLanguage, Question};
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::OrigaError;
+
+const SMALL_KANA: &str = "ゃゅょぁぃぅぇぉゎャュョァィゥェォヮ";
+
+/// Тип ударения по месту падения тона
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PitchAccentPattern {
+    /// Без падения: тон остается высоким и на частице
+    Heiban,
+    /// Падение после первой моры
+    Atamadaka,
+    /// Падение внутри слова
+    Nakadaka,
+    /// Падение после последней моры: частица произносится низко
+    Odaka,
+}
+
+/// Мора чтения с высотой тона
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PitchMora {
+    text: String,
+    is_high: bool,
+    is_downstep: bool,
+}
+
+impl PitchMora {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn is_high(&self) -> bool {
+        self.is_high
+    }
+
+    /// После этой моры тон падает
+    pub fn is_downstep(&self) -> bool {
+        self.is_downstep
+    }
+}
+
+/// Ударение одного чтения в нотации словарей: номер моры,
+/// после которой тон падает, 0 — падения нет
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "PitchAccentStoredType")]
+pub struct PitchAccent {
+    reading: String,
+    downstep: usize,
+}
+
+#[derive(Deserialize)]
+struct PitchAccentStoredType {
+    reading: String,
+    downstep: usize,
+}
+
+impl TryFrom<PitchAccentStoredType> for PitchAccent {
+    type Error = OrigaError;
+
+    fn try_from(stored: PitchAccentStoredType) -> Result<Self, Self::Error> {
+        PitchAccent::new(stored.reading, stored.downstep)
+    }
+}
+
+impl PitchAccent {
+    pub fn new(reading: String, downstep: usize) -> Result<Self, OrigaError> {
+        let morae_count = split_morae(&reading).len();
+        if morae_count == 0 || downstep > morae_count {
+            return Err(OrigaError::InvalidValues {
+                reason: format!(
+                    "Downstep {} is out of range for reading {}",
+                    downstep, reading
+                ),
+            });
+        }
+
+        Ok(Self { reading, downstep })
+    }
+
+    pub fn reading(&self) -> &str {
+        &self.reading
+    }
+
+    pub fn downstep(&self) -> usize {
+        self.downstep
+    }
+
+    pub fn pattern(&self) -> PitchAccentPattern {
+        match self.downstep {
+            0 => PitchAccentPattern::Heiban,
+            1 => PitchAccentPattern::Atamadaka,
+            n if n == split_morae(&self.reading).len() => PitchAccentPattern::Odaka,
+            _ => PitchAccentPattern::Nakadaka,
+        }
+    }
+
+    /// Моры чтения с высотой тона: первая мора низкая, если ударение
+    /// не на ней, затем тон высокий до моры падения включительно
+    pub fn morae(&self) -> Vec<PitchMora> {
+        split_morae(&self.reading)
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let position = i + 1;
+                let is_high = match self.downstep {
+                    0 => position > 1,
+                    1 => position == 1,
+                    n => position > 1 && position <= n,
+                };
+                PitchMora {
+                    text,
+                    is_high,
+                    is_downstep: position == self.downstep,
+                }
+            })
+            .collect()
+    }
+
+    /// Высота тона частицы после слова, например が
+    pub fn is_particle_high(&self) -> bool {
+        self.pattern() == PitchAccentPattern::Heiban
+    }
+}
+
+/// Делит чтение каной на моры: маленькие ゃ, ゅ, ょ и подобные
+/// относятся к предыдущей море, а っ, ん и ー считаются отдельными
+pub fn split_morae(reading: &str) -> Vec<String> {
+    let mut morae: Vec<String> = Vec::new();
+    for c in reading.chars() {
+        match morae.last_mut() {
+            Some(last) if SMALL_KANA.contains(c) => last.push(c),
+            _ => morae.push(c.to_string()),
+        }
+    }
+    morae
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marks(accent: &PitchAccent) -> String {
+        accent
+            .morae()
+            .iter()
+            .map(|mora| if mora.is_high() { 'H' } else { 'L' })
+            .collect()
+    }
+
+    #[test]
+    fn should_split_small_kana_into_previous_mora() {
+        assert_eq!(split_morae("きょうっと"), vec!["きょ", "う", "っ", "と"]);
+        assert_eq!(split_morae("シャツ"), vec!["シャ", "ツ"]);
+    }
+
+    #[test]
+    fn should_mark_high_and_low_morae() {
+        let heiban = PitchAccent::new("さくら".to_string(), 0).unwrap();
+        let atamadaka = PitchAccent::new("いのち".to_string(), 1).unwrap();
+        let nakadaka = PitchAccent::new("おかし".to_string(), 2).unwrap();
+        let odaka = PitchAccent::new("おとうと".to_string(), 4).unwrap();
+
+        assert_eq!(marks(&heiban), "LHH");
+        assert_eq!(marks(&atamadaka), "HLL");
+        assert_eq!(marks(&nakadaka), "LHL");
+        assert_eq!(marks(&odaka), "LHHH");
+        assert!(heiban.is_particle_high());
+        assert!(!odaka.is_particle_high());
+        assert_eq!(odaka.pattern(), PitchAccentPattern::Odaka);
+        assert!(nakadaka.morae()[1].is_downstep());
+    }
+
+    #[test]
+    fn should_reject_downstep_beyond_reading() {
+        assert!(PitchAccent::new("はし".to_string(), 3).is_err());
+        assert!(
+            serde_json::from_str::<PitchAccent>(r#"{"reading": "はし", "downstep": 3}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<PitchAccent>(r#"{"reading": "はし", "downstep": 2}"#).is_ok()
+        );
+    }
+}
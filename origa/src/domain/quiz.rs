@@ -0,0 +1,291 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    sync::LazyLock,
+};
+
+use rand::seq::SliceRandom;
+
+use crate::domain::{
+    Card, JapaneseLevel, KnowledgeSet, NativeLanguage, OrigaError, VOCABULARY_DICTIONARY,
+    VocabularyCard,
+    japanese::{JapaneseChar, JapaneseText},
+    tokenizer::{PartOfSpeech, tokenize_text},
+};
+
+const OPTIONS_COUNT: usize = 4;
+const SHORTLIST_SIZE: usize = 30;
+
+/// Уровни JLPT слов словаря. Уровень слова без своего уровня ищется по кандзи,
+/// поэтому он считается один раз, а не для каждой карточки урока
+static DICTIONARY_LEVELS: LazyLock<HashMap<&'static str, JapaneseLevel>> = LazyLock::new(|| {
+    VOCABULARY_DICTIONARY
+        .words()
+        .filter_map(|info| Some((info.word(), VOCABULARY_DICTIONARY.word_level(info.word())?)))
+        .collect()
+});
+
+/// Что пользователь выбирает из вариантов
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuizKind {
+    /// Значение японского слова
+    Meaning,
+    /// Чтение японского слова каной
+    Reading,
+    /// Японское слово по значению
+    Word,
+}
+
+/// Вопрос с выбором одного из нескольких вариантов ответа
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quiz {
+    kind: QuizKind,
+    options: Vec<String>,
+    correct_index: usize,
+}
+
+impl Quiz {
+    pub fn kind(&self) -> QuizKind {
+        self.kind
+    }
+
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    pub fn correct_index(&self) -> usize {
+        self.correct_index
+    }
+
+    pub fn is_correct(&self, index: usize) -> bool {
+        index == self.correct_index
+    }
+}
+
+struct Candidate<'a> {
+    word: &'a str,
+    meaning: &'a str,
+    score: usize,
+}
+
+/// Подбирает правдоподобные неверные варианты: слова с общими кандзи,
+/// того же уровня JLPT и той же части речи из словаря и карточек пользователя
+pub struct QuizBuilder<'a> {
+    knowledge_set: &'a KnowledgeSet,
+    lang: &'a NativeLanguage,
+    parts_of_speech: RefCell<HashMap<String, Option<PartOfSpeech>>>,
+}
+
+impl<'a> QuizBuilder<'a> {
+    pub fn new(knowledge_set: &'a KnowledgeSet, lang: &'a NativeLanguage) -> Self {
+        Self {
+            knowledge_set,
+            lang,
+            parts_of_speech: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn build(&self, card: &VocabularyCard, kind: QuizKind) -> Result<Quiz, OrigaError> {
+        let word = card.word().text();
+        let correct = match kind {
+            QuizKind::Meaning => card.meaning().text().to_string(),
+            QuizKind::Reading => word.as_reading()?,
+            QuizKind::Word => word.to_string(),
+        };
+
+        let candidates = rank_candidates(word, self.candidates(word), word_level, |word| {
+            self.part_of_speech(word)
+        });
+        let distractors = candidates.into_iter().filter_map(|candidate| match kind {
+            QuizKind::Meaning => Some(candidate.meaning.to_string()),
+            QuizKind::Reading => candidate.word.as_reading().ok(),
+            QuizKind::Word => Some(candidate.word.to_string()),
+        });
+
+        quiz_with_distractors(kind, correct, distractors).ok_or(OrigaError::InvalidValues {
+            reason: format!("Not enough distractors for word {}", word),
+        })
+    }
+
+    /// Кандидаты ссылаются на слова карточек и словаря, строки создаются
+    /// только для выбранных неверных вариантов
+    fn candidates(&self, word: &str) -> Vec<Candidate<'a>> {
+        let own_words = self
+            .knowledge_set
+            .study_cards()
+            .values()
+            .filter_map(|card| match card.card() {
+                Card::Vocabulary(vocabulary) => Some(Candidate {
+                    word: vocabulary.word().text(),
+                    meaning: vocabulary.meaning().text(),
+                    score: 1,
+                }),
+                _ => None,
+            });
+
+        let dictionary_words = VOCABULARY_DICTIONARY.words().map(|info| Candidate {
+            word: info.word(),
+            meaning: info.translation(self.lang),
+            score: 0,
+        });
+
+        let mut seen = HashSet::new();
+        own_words
+            .chain(dictionary_words)
+            .filter(|candidate| candidate.word != word && seen.insert(candidate.word))
+            .collect()
+    }
+
+    /// Часть речи слова, токенизатор запускается один раз на слово за урок
+    fn part_of_speech(&self, word: &str) -> Option<PartOfSpeech> {
+        if let Some(part) = self.parts_of_speech.borrow().get(word) {
+            return part.clone();
+        }
+
+        let part = part_of_speech(word);
+        self.parts_of_speech
+            .borrow_mut()
+            .insert(word.to_string(), part.clone());
+        part
+    }
+}
+
+/// Сортирует кандидатов по правдоподобию: общие кандзи со словом, тот же уровень JLPT,
+/// а среди первых `SHORTLIST_SIZE` еще и та же часть речи
+fn rank_candidates<'a>(
+    word: &str,
+    mut candidates: Vec<Candidate<'a>>,
+    word_level: impl Fn(&str) -> Option<JapaneseLevel>,
+    part_of_speech: impl Fn(&str) -> Option<PartOfSpeech>,
+) -> Vec<Candidate<'a>> {
+    let kanji: HashSet<char> = word.chars().filter(|c| c.is_kanji()).collect();
+    let level = word_level(word);
+
+    for candidate in candidates.iter_mut() {
+        if candidate.word.chars().any(|c| kanji.contains(&c)) {
+            candidate.score += 2;
+        }
+        if level.is_some() && word_level(candidate.word) == level {
+            candidate.score += 1;
+        }
+    }
+
+    candidates.shuffle(&mut rand::rng());
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.score));
+    candidates.truncate(SHORTLIST_SIZE);
+
+    let word_part = part_of_speech(word);
+    for candidate in candidates.iter_mut() {
+        if word_part.is_some() && part_of_speech(candidate.word) == word_part {
+            candidate.score += 2;
+        }
+    }
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.score));
+
+    candidates
+}
+
+/// Вопрос из верного ответа и первых подходящих неверных вариантов:
+/// пустые и повторяющиеся варианты пропускаются
+fn quiz_with_distractors(
+    kind: QuizKind,
+    correct: String,
+    distractors: impl Iterator<Item = String>,
+) -> Option<Quiz> {
+    let mut options = vec![correct.clone()];
+    for option in distractors {
+        if options.len() == OPTIONS_COUNT {
+            break;
+        }
+        if !option.is_empty() && !options.contains(&option) {
+            options.push(option);
+        }
+    }
+
+    if options.len() < OPTIONS_COUNT {
+        return None;
+    }
+
+    options.shuffle(&mut rand::rng());
+    let correct_index = options
+        .iter()
+        .position(|option| *option == correct)
+        .unwrap_or_default();
+
+    Some(Quiz {
+        kind,
+        options,
+        correct_index,
+    })
+}
+
+fn word_level(word: &str) -> Option<JapaneseLevel> {
+    DICTIONARY_LEVELS
+        .get(word)
+        .copied()
+        .or_else(|| VOCABULARY_DICTIONARY.word_level(word))
+}
+
+fn part_of_speech(word: &str) -> Option<PartOfSpeech> {
+    tokenize_text(word)
+        .ok()?
+        .first()
+        .map(|token| token.part_of_speech().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(word: &str) -> Candidate<'_> {
+        Candidate {
+            word,
+            meaning: "",
+            score: 0,
+        }
+    }
+
+    #[test]
+    fn should_rank_candidates_by_kanji_level_and_part_of_speech() {
+        let level = |word: &str| match word {
+            "学生" | "学校" | "猫" | "食べる" => Some(JapaneseLevel::N5),
+            _ => Some(JapaneseLevel::N1),
+        };
+        let part = |word: &str| match word {
+            "食べる" => Some(PartOfSpeech::Verb),
+            _ => Some(PartOfSpeech::Noun),
+        };
+        let candidates = ["食べる", "猫", "先生", "学校"].map(candidate).into();
+
+        let ranked: Vec<_> = rank_candidates("学生", candidates, level, part)
+            .into_iter()
+            .map(|candidate| candidate.word)
+            .collect();
+
+        assert_eq!(ranked, vec!["学校", "先生", "猫", "食べる"]);
+    }
+
+    #[test]
+    fn should_skip_empty_and_repeated_distractors() {
+        let distractors = ["", "がくせい", "せんせい", "せんせい", "ねこ", "いぬ"]
+            .map(str::to_string)
+            .into_iter();
+
+        let quiz =
+            quiz_with_distractors(QuizKind::Reading, "がくせい".to_string(), distractors).unwrap();
+
+        let mut options = quiz.options().to_vec();
+        options.sort();
+        assert_eq!(options, vec!["いぬ", "がくせい", "せんせい", "ねこ"]);
+        assert!(quiz.is_correct(quiz.options().iter().position(|o| o == "がくせい").unwrap()));
+    }
+
+    #[test]
+    fn should_fail_without_enough_distractors() {
+        let distractors = ["せんせい", "ねこ"].map(str::to_string).into_iter();
+
+        assert!(
+            quiz_with_distractors(QuizKind::Reading, "がくせい".to_string(), distractors).is_none()
+        );
+    }
+}
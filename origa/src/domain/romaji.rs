@@ -0,0 +1,441 @@
+use crate::domain::japanese::JapaneseText;
+
+/// Соответствия ромадзи и хираганы. Для каждой каны первым идет вариант Хепберна,
+/// он используется при обратном преобразовании. Остальные варианты — Кунрэй и
+/// написания, принятые в японских IME.
+const ROMAJI_TABLE: &[(&str, &str)] = &[
+    // Ёон
+    ("kya", "きゃ"),
+    ("kyu", "きゅ"),
+    ("kyo", "きょ"),
+    ("gya", "ぎゃ"),
+    ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"),
+    ("sha", "しゃ"),
+    ("shu", "しゅ"),
+    ("sho", "しょ"),
+    ("she", "しぇ"),
+    ("sya", "しゃ"),
+    ("syu", "しゅ"),
+    ("syo", "しょ"),
+    ("ja", "じゃ"),
+    ("ju", "じゅ"),
+    ("jo", "じょ"),
+    ("je", "じぇ"),
+    ("zya", "じゃ"),
+    ("zyu", "じゅ"),
+    ("zyo", "じょ"),
+    ("jya", "じゃ"),
+    ("jyu", "じゅ"),
+    ("jyo", "じょ"),
+    ("cha", "ちゃ"),
+    ("chu", "ちゅ"),
+    ("cho", "ちょ"),
+    ("che", "ちぇ"),
+    ("tya", "ちゃ"),
+    ("tyu", "ちゅ"),
+    ("tyo", "ちょ"),
+    ("cya", "ちゃ"),
+    ("cyu", "ちゅ"),
+    ("cyo", "ちょ"),
+    ("dya", "ぢゃ"),
+    ("dyu", "ぢゅ"),
+    ("dyo", "ぢょ"),
+    ("nya", "にゃ"),
+    ("nyu", "にゅ"),
+    ("nyo", "にょ"),
+    ("hya", "ひゃ"),
+    ("hyu", "ひゅ"),
+    ("hyo", "ひょ"),
+    ("bya", "びゃ"),
+    ("byu", "びゅ"),
+    ("byo", "びょ"),
+    ("pya", "ぴゃ"),
+    ("pyu", "ぴゅ"),
+    ("pyo", "ぴょ"),
+    ("mya", "みゃ"),
+    ("myu", "みゅ"),
+    ("myo", "みょ"),
+    ("rya", "りゃ"),
+    ("ryu", "りゅ"),
+    ("ryo", "りょ"),
+    // Звуки заимствованных слов
+    ("fa", "ふぁ"),
+    ("fi", "ふぃ"),
+    ("fe", "ふぇ"),
+    ("fo", "ふぉ"),
+    ("thi", "てぃ"),
+    ("dhi", "でぃ"),
+    ("twu", "とぅ"),
+    ("dwu", "どぅ"),
+    ("wi", "うぃ"),
+    ("we", "うぇ"),
+    ("va", "ゔぁ"),
+    ("vi", "ゔぃ"),
+    ("vu", "ゔ"),
+    ("ve", "ゔぇ"),
+    ("vo", "ゔぉ"),
+    // Основная таблица
+    ("a", "あ"),
+    ("i", "い"),
+    ("u", "う"),
+    ("e", "え"),
+    ("o", "お"),
+    ("ka", "か"),
+    ("ki", "き"),
+    ("ku", "く"),
+    ("ke", "け"),
+    ("ko", "こ"),
+    ("ga", "が"),
+    ("gi", "ぎ"),
+    ("gu", "ぐ"),
+    ("ge", "げ"),
+    ("go", "ご"),
+    ("sa", "さ"),
+    ("shi", "し"),
+    ("si", "し"),
+    ("su", "す"),
+    ("se", "せ"),
+    ("so", "そ"),
+    ("za", "ざ"),
+    ("ji", "じ"),
+    ("zi", "じ"),
+    ("zu", "ず"),
+    ("ze", "ぜ"),
+    ("zo", "ぞ"),
+    ("ta", "た"),
+    ("chi", "ち"),
+    ("ti", "ち"),
+    ("tsu", "つ"),
+    ("tu", "つ"),
+    ("te", "て"),
+    ("to", "と"),
+    ("da", "だ"),
+    ("de", "で"),
+    ("do", "ど"),
+    ("na", "な"),
+    ("ni", "に"),
+    ("nu", "ぬ"),
+    ("ne", "ね"),
+    ("no", "の"),
+    ("ha", "は"),
+    ("hi", "ひ"),
+    ("fu", "ふ"),
+    ("hu", "ふ"),
+    ("he", "へ"),
+    ("ho", "ほ"),
+    ("ba", "ば"),
+    ("bi", "び"),
+    ("bu", "ぶ"),
+    ("be", "べ"),
+    ("bo", "ぼ"),
+    ("pa", "ぱ"),
+    ("pi", "ぴ"),
+    ("pu", "ぷ"),
+    ("pe", "ぺ"),
+    ("po", "ぽ"),
+    ("ma", "ま"),
+    ("mi", "み"),
+    ("mu", "む"),
+    ("me", "め"),
+    ("mo", "も"),
+    ("ya", "や"),
+    ("yu", "ゆ"),
+    ("yo", "よ"),
+    ("ra", "ら"),
+    ("ri", "り"),
+    ("ru", "る"),
+    ("re", "れ"),
+    ("ro", "ろ"),
+    ("wa", "わ"),
+    ("wo", "を"),
+    ("ji", "ぢ"),
+    ("zu", "づ"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("wi", "ゐ"),
+    ("we", "ゑ"),
+    // Маленькая кана
+    ("xtsu", "っ"),
+    ("ltsu", "っ"),
+    ("xtu", "っ"),
+    ("ltu", "っ"),
+    ("xya", "ゃ"),
+    ("xyu", "ゅ"),
+    ("xyo", "ょ"),
+    ("lya", "ゃ"),
+    ("lyu", "ゅ"),
+    ("lyo", "ょ"),
+    ("xwa", "ゎ"),
+    ("lwa", "ゎ"),
+    ("xa", "ぁ"),
+    ("xi", "ぃ"),
+    ("xu", "ぅ"),
+    ("xe", "ぇ"),
+    ("xo", "ぉ"),
+    ("la", "ぁ"),
+    ("li", "ぃ"),
+    ("lu", "ぅ"),
+    ("le", "ぇ"),
+    ("lo", "ぉ"),
+    // Знаки препинания
+    ("-", "ー"),
+    (".", "。"),
+    (",", "、"),
+];
+
+const MAX_ROMAJI_LENGTH: usize = 4;
+const LONG_VOWELS: &str = "āīūēōâîûêô";
+
+/// Переводит ромадзи в хирагану. Понимает Хепберна, Кунрэй и написания IME:
+/// удвоенные согласные, `n'` и `nn` для ん, `m` перед b и p, макроны и
+/// циркумфлексы долгих гласных.
+/// Символы, которые не удалось распознать, остаются как есть.
+pub fn romaji_to_hiragana(text: &str) -> String {
+    let chars: Vec<char> = expand_long_vowels(&text.to_lowercase()).chars().collect();
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c == 'n' || (c == 'm' && next.is_some_and(|n| matches!(n, 'b' | 'p'))) {
+            if c == 'n' && next == Some('\'') {
+                result.push('ん');
+                i += 2;
+                continue;
+            }
+            if c == 'n' && next == Some('n') {
+                let after = chars.get(i + 2).copied();
+                result.push('ん');
+                i += if after.is_some_and(|a| is_vowel(a) || a == 'y') {
+                    1
+                } else {
+                    2
+                };
+                continue;
+            }
+            if !next.is_some_and(|n| is_vowel(n) || n == 'y') {
+                result.push('ん');
+                i += 1;
+                continue;
+            }
+        }
+
+        if c.is_ascii_alphabetic() && !is_vowel(c) && c != 'n' {
+            let doubled = next == Some(c) || (c == 't' && next == Some('c'));
+            if doubled && chars.get(i + 2).is_some_and(|a| a.is_ascii_alphabetic()) {
+                result.push('っ');
+                i += 1;
+                continue;
+            }
+        }
+
+        if let Some((length, kana)) = match_romaji(&chars[i..]) {
+            result.push_str(kana);
+            i += length;
+        } else {
+            result.push(c);
+            i += 1;
+        }
+    }
+
+    result
+}
+
+/// Переводит ромадзи в катакану, долгие гласные записываются через ー
+pub fn romaji_to_katakana(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .map(|c| match c {
+            'ā' | 'ī' | 'ū' | 'ē' | 'ō' | 'â' | 'î' | 'û' | 'ê' | 'ô' => {
+                format!("{}-", strip_long_vowel(c))
+            }
+            c => c.to_string(),
+        })
+        .collect();
+
+    hiragana_to_katakana(&romaji_to_hiragana(&text))
+}
+
+/// Переводит хирагану и катакану в ромадзи по Хепберну
+pub fn kana_to_romaji(text: &str) -> String {
+    let chars: Vec<char> = text.to_hiragana().chars().collect();
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            'っ' => {
+                if let Some((_, romaji)) = match_kana(&chars[i + 1..]) {
+                    let first = if romaji.starts_with("ch") {
+                        't'
+                    } else {
+                        romaji.chars().next().unwrap_or_default()
+                    };
+                    if !is_vowel(first) {
+                        result.push(first);
+                    }
+                }
+                i += 1;
+            }
+            'ん' => {
+                result.push('n');
+                if match_kana(&chars[i + 1..])
+                    .is_some_and(|(_, romaji)| romaji.starts_with(|c| is_vowel(c) || c == 'y'))
+                {
+                    result.push('\'');
+                }
+                i += 1;
+            }
+            'ー' => {
+                if let Some(vowel) = result.chars().last().filter(|c| is_vowel(*c)) {
+                    result.push(vowel);
+                }
+                i += 1;
+            }
+            c => {
+                if let Some((length, romaji)) = match_kana(&chars[i..]) {
+                    result.push_str(romaji);
+                    i += length;
+                } else {
+                    result.push(c);
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Похоже ли введенное на ромадзи, которое стоит перевести в кану
+pub fn is_romaji(text: &str) -> bool {
+    !text.trim().is_empty()
+        && text.chars().all(|c| {
+            c.is_ascii_alphabetic()
+                || LONG_VOWELS.contains(c)
+                || c.is_whitespace()
+                || "-'.,".contains(c)
+        })
+        && !text.contains_japanese()
+}
+
+fn match_romaji(chars: &[char]) -> Option<(usize, &'static str)> {
+    (1..=MAX_ROMAJI_LENGTH.min(chars.len()))
+        .rev()
+        .find_map(|length| {
+            let romaji: String = chars[..length].iter().collect();
+            ROMAJI_TABLE
+                .iter()
+                .find(|(r, _)| *r == romaji)
+                .map(|(_, kana)| (length, *kana))
+        })
+}
+
+fn match_kana(chars: &[char]) -> Option<(usize, &'static str)> {
+    (1..=2.min(chars.len())).rev().find_map(|length| {
+        let kana: String = chars[..length].iter().collect();
+        ROMAJI_TABLE
+            .iter()
+            .find(|(_, k)| *k == kana)
+            .map(|(romaji, _)| (length, *romaji))
+    })
+}
+
+fn expand_long_vowels(text: &str) -> String {
+    text.chars()
+        .flat_map(|c| match c {
+            'ā' | 'â' => vec!['a', 'a'],
+            'ī' | 'î' => vec!['i', 'i'],
+            'ū' | 'û' => vec!['u', 'u'],
+            'ē' | 'ê' => vec!['e', 'e'],
+            'ō' | 'ô' => vec!['o', 'u'],
+            c => vec![c],
+        })
+        .collect()
+}
+
+/// Долгое э в словах вроде せんせい записывается каной い: ē читается как ei
+pub(crate) fn long_e_as_ei(text: &str) -> String {
+    text.replace(['ē', 'ê'], "ei")
+}
+
+/// Долгое о в словах вроде おおきい записывается каной お: ō читается как oo
+pub(crate) fn long_o_as_oo(text: &str) -> String {
+    text.replace(['ō', 'ô'], "oo")
+}
+
+fn strip_long_vowel(c: char) -> char {
+    match c {
+        'ā' | 'â' => 'a',
+        'ī' | 'î' => 'i',
+        'ū' | 'û' => 'u',
+        'ē' | 'ê' => 'e',
+        'ō' | 'ô' => 'o',
+        c => c,
+    }
+}
+
+fn hiragana_to_katakana(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if ('\u{3041}'..='\u{3096}').contains(&c) {
+                char::from_u32(c as u32 + 0x60).unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_hepburn_and_kunrei() {
+        assert_eq!(romaji_to_hiragana("shinbun"), "しんぶん");
+        assert_eq!(romaji_to_hiragana("sinbun"), "しんぶん");
+        assert_eq!(romaji_to_hiragana("tsukue"), "つくえ");
+        assert_eq!(romaji_to_hiragana("tukue"), "つくえ");
+    }
+
+    #[test]
+    fn should_convert_small_tsu() {
+        assert_eq!(romaji_to_hiragana("gakkou"), "がっこう");
+        assert_eq!(romaji_to_hiragana("matcha"), "まっちゃ");
+    }
+
+    #[test]
+    fn should_convert_n_before_vowels() {
+        assert_eq!(romaji_to_hiragana("kon'ya"), "こんや");
+        assert_eq!(romaji_to_hiragana("shimbun"), "しんぶん");
+        assert_eq!(romaji_to_hiragana("konnichiwa"), "こんにちわ");
+        assert_eq!(romaji_to_hiragana("kanna"), "かんな");
+    }
+
+    #[test]
+    fn should_convert_long_vowels() {
+        assert_eq!(romaji_to_hiragana("tōkyō"), "とうきょう");
+        assert_eq!(romaji_to_hiragana("onēsan"), "おねえさん");
+        assert_eq!(romaji_to_hiragana(&long_e_as_ei("sensē")), "せんせい");
+        assert_eq!(romaji_to_hiragana(&long_o_as_oo("ōkii")), "おおきい");
+        assert_eq!(romaji_to_katakana("kōhī"), "コーヒー");
+        assert_eq!(romaji_to_katakana("ko-hi-"), "コーヒー");
+    }
+
+    #[test]
+    fn should_convert_kana_to_romaji() {
+        assert_eq!(kana_to_romaji("きって"), "kitte");
+        assert_eq!(kana_to_romaji("まっちゃ"), "matcha");
+        assert_eq!(kana_to_romaji("こんや"), "kon'ya");
+        assert_eq!(kana_to_romaji("コーヒー"), "koohii");
+        assert_eq!(kana_to_romaji("しんぶん"), "shinbun");
+    }
+}
@@ -1,9 +1,19 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::domain::{Card, OrigaError};
+
+/// Число весов в модели FSRS
+const SRS_WEIGHTS_COUNT: usize = 19;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct UserSettings {
     llm: LlmSettings,
     duolingo_jwt_token: Option<String>,
+    #[serde(default)]
+    srs_parameters: Option<SrsParameters>,
+    #[serde(default)]
+    study_plan: StudyPlan,
 }
 
 impl UserSettings {
@@ -11,6 +21,8 @@ impl UserSettings {
         Self {
             llm,
             duolingo_jwt_token,
+            srs_parameters: None,
+            study_plan: StudyPlan::default(),
         }
     }
 
@@ -18,6 +30,8 @@ impl UserSettings {
         Self {
             duolingo_jwt_token: None,
             llm: LlmSettings::None,
+            srs_parameters: None,
+            study_plan: StudyPlan::default(),
         }
     }
 
@@ -36,6 +50,337 @@ impl UserSettings {
     pub fn set_duolingo_jwt_token(&mut self, token: Option<String>) {
         self.duolingo_jwt_token = token;
     }
+
+    pub fn srs_parameters(&self) -> Option<&SrsParameters> {
+        self.srs_parameters.as_ref()
+    }
+
+    pub fn set_srs_parameters(&mut self, srs_parameters: Option<SrsParameters>) {
+        self.srs_parameters = srs_parameters;
+    }
+
+    pub fn study_plan(&self) -> &StudyPlan {
+        &self.study_plan
+    }
+
+    pub fn set_study_plan(&mut self, study_plan: StudyPlan) {
+        self.study_plan = study_plan;
+    }
+}
+
+/// План занятий: сколько и каких карточек попадает в урок и закрепление
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "StudyPlanStoredType")]
+pub struct StudyPlan {
+    new_cards_per_day: usize,
+    max_reviews_per_lesson: usize,
+    fixation_cards_limit: usize,
+    card_mix: CardMix,
+    order: LessonOrder,
+    bury_siblings: bool,
+    multiple_choice: bool,
+}
+
+#[derive(Deserialize)]
+struct StudyPlanStoredType {
+    new_cards_per_day: usize,
+    max_reviews_per_lesson: usize,
+    fixation_cards_limit: usize,
+    card_mix: CardMix,
+    order: LessonOrder,
+    #[serde(default = "StudyPlan::default_bury_siblings")]
+    bury_siblings: bool,
+    #[serde(default)]
+    multiple_choice: bool,
+}
+
+impl TryFrom<StudyPlanStoredType> for StudyPlan {
+    type Error = OrigaError;
+
+    fn try_from(stored: StudyPlanStoredType) -> Result<Self, Self::Error> {
+        StudyPlan::new(
+            stored.new_cards_per_day,
+            stored.max_reviews_per_lesson,
+            stored.fixation_cards_limit,
+            stored.card_mix,
+            stored.order,
+            stored.bury_siblings,
+            stored.multiple_choice,
+        )
+    }
+}
+
+impl Default for StudyPlan {
+    fn default() -> Self {
+        Self {
+            new_cards_per_day: 7,
+            max_reviews_per_lesson: 100,
+            fixation_cards_limit: 15,
+            card_mix: CardMix::default(),
+            order: LessonOrder::default(),
+            bury_siblings: Self::default_bury_siblings(),
+            multiple_choice: false,
+        }
+    }
+}
+
+impl StudyPlan {
+    pub fn new(
+        new_cards_per_day: usize,
+        max_reviews_per_lesson: usize,
+        fixation_cards_limit: usize,
+        card_mix: CardMix,
+        order: LessonOrder,
+        bury_siblings: bool,
+        multiple_choice: bool,
+    ) -> Result<Self, OrigaError> {
+        if max_reviews_per_lesson == 0 || fixation_cards_limit == 0 {
+            return Err(OrigaError::InvalidValues {
+                reason: "Lesson and fixation limits must be greater than zero".to_string(),
+            });
+        }
+
+        Ok(Self {
+            new_cards_per_day,
+            max_reviews_per_lesson,
+            fixation_cards_limit,
+            card_mix,
+            order,
+            bury_siblings,
+            multiple_choice,
+        })
+    }
+
+    fn default_bury_siblings() -> bool {
+        true
+    }
+
+    pub fn new_cards_per_day(&self) -> usize {
+        self.new_cards_per_day
+    }
+
+    pub fn max_reviews_per_lesson(&self) -> usize {
+        self.max_reviews_per_lesson
+    }
+
+    pub fn fixation_cards_limit(&self) -> usize {
+        self.fixation_cards_limit
+    }
+
+    pub fn card_mix(&self) -> &CardMix {
+        &self.card_mix
+    }
+
+    pub fn order(&self) -> &LessonOrder {
+        &self.order
+    }
+
+    /// Не показывать в одном уроке связанные карточки, например 学生 и 学
+    pub fn bury_siblings(&self) -> bool {
+        self.bury_siblings
+    }
+
+    /// Показывать карточки словаря как выбор из нескольких вариантов
+    pub fn multiple_choice(&self) -> bool {
+        self.multiple_choice
+    }
+}
+
+/// Относительные доли словаря, кандзи и грамматики в уроке.
+/// Карточки предложений и пропусков входят в долю словаря
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "CardMixStoredType")]
+pub struct CardMix {
+    vocabulary: u32,
+    kanji: u32,
+    grammar: u32,
+}
+
+#[derive(Deserialize)]
+struct CardMixStoredType {
+    vocabulary: u32,
+    kanji: u32,
+    grammar: u32,
+}
+
+impl TryFrom<CardMixStoredType> for CardMix {
+    type Error = OrigaError;
+
+    fn try_from(stored: CardMixStoredType) -> Result<Self, Self::Error> {
+        CardMix::new(stored.vocabulary, stored.kanji, stored.grammar)
+    }
+}
+
+impl Default for CardMix {
+    fn default() -> Self {
+        Self {
+            vocabulary: 1,
+            kanji: 1,
+            grammar: 1,
+        }
+    }
+}
+
+impl CardMix {
+    pub fn new(vocabulary: u32, kanji: u32, grammar: u32) -> Result<Self, OrigaError> {
+        let total = vocabulary
+            .checked_add(kanji)
+            .and_then(|total| total.checked_add(grammar))
+            .ok_or(OrigaError::InvalidValues {
+                reason: "Card mix shares are too large".to_string(),
+            })?;
+        if total == 0 {
+            return Err(OrigaError::InvalidValues {
+                reason: "Card mix must contain at least one card type".to_string(),
+            });
+        }
+
+        Ok(Self {
+            vocabulary,
+            kanji,
+            grammar,
+        })
+    }
+
+    pub fn vocabulary(&self) -> u32 {
+        self.vocabulary
+    }
+
+    pub fn kanji(&self) -> u32 {
+        self.kanji
+    }
+
+    pub fn grammar(&self) -> u32 {
+        self.grammar
+    }
+
+    fn weight(&self, card: &Card) -> u32 {
+        match card {
+            Card::Vocabulary(_) | Card::Sentence(_) | Card::Cloze(_) => self.vocabulary,
+            Card::Kanji(_) => self.kanji,
+            Card::Grammar(_) => self.grammar,
+        }
+    }
+
+    /// Тип карточки входит в выбор новых карточек
+    pub(crate) fn includes(&self, card: &Card) -> bool {
+        self.weight(card) > 0
+    }
+
+    /// Отбирает не больше `limit` карточек: сначала по квоте каждого типа,
+    /// затем добирает оставшиеся места в исходном порядке карточками любых
+    /// типов, чтобы повторения типов с нулевой долей не копились.
+    pub(crate) fn take<T>(
+        &self,
+        items: Vec<T>,
+        limit: usize,
+        card: impl Fn(&T) -> &Card,
+    ) -> Vec<T> {
+        let total = self.vocabulary as u64 + self.kanji as u64 + self.grammar as u64;
+        if total == 0 {
+            return Vec::new();
+        }
+
+        let quota = |weight: u32| (limit as u64 * weight as u64 / total) as usize;
+        let mut taken = vec![false; items.len()];
+        let mut counts = [0usize; 3];
+        let mut selected = 0;
+
+        for (i, item) in items.iter().enumerate() {
+            let card = card(item);
+            let kind = match card {
+                Card::Vocabulary(_) | Card::Sentence(_) | Card::Cloze(_) => 0,
+                Card::Kanji(_) => 1,
+                Card::Grammar(_) => 2,
+            };
+            if selected < limit && counts[kind] < quota(self.weight(card)) {
+                counts[kind] += 1;
+                taken[i] = true;
+                selected += 1;
+            }
+        }
+
+        for taken in taken.iter_mut().filter(|taken| !**taken) {
+            if selected >= limit {
+                break;
+            }
+            *taken = true;
+            selected += 1;
+        }
+
+        items
+            .into_iter()
+            .zip(taken)
+            .filter_map(|(item, taken)| taken.then_some(item))
+            .collect()
+    }
+}
+
+/// Порядок показа карточек в уроке
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LessonOrder {
+    #[default]
+    Shuffled,
+    DueDate,
+    Difficulty,
+}
+
+/// Веса FSRS, подобранные по истории повторений конкретного пользователя
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SrsParametersStoredType")]
+pub struct SrsParameters {
+    weights: Vec<f64>,
+    reviews_count: usize,
+    optimized_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct SrsParametersStoredType {
+    weights: Vec<f64>,
+    reviews_count: usize,
+    optimized_at: DateTime<Utc>,
+}
+
+impl TryFrom<SrsParametersStoredType> for SrsParameters {
+    type Error = OrigaError;
+
+    fn try_from(stored: SrsParametersStoredType) -> Result<Self, Self::Error> {
+        let mut parameters = SrsParameters::new(stored.weights, stored.reviews_count)?;
+        parameters.optimized_at = stored.optimized_at;
+        Ok(parameters)
+    }
+}
+
+impl SrsParameters {
+    pub fn new(weights: Vec<f64>, reviews_count: usize) -> Result<Self, OrigaError> {
+        if weights.len() != SRS_WEIGHTS_COUNT || weights.iter().any(|w| !w.is_finite()) {
+            return Err(OrigaError::InvalidValues {
+                reason: format!(
+                    "SRS weights must be {} finite numbers, got {}",
+                    SRS_WEIGHTS_COUNT,
+                    weights.len()
+                ),
+            });
+        }
+
+        Ok(Self {
+            weights,
+            reviews_count,
+            optimized_at: Utc::now(),
+        })
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    pub fn reviews_count(&self) -> usize {
+        self.reviews_count
+    }
+
+    pub fn optimized_at(&self) -> DateTime<Utc> {
+        self.optimized_at
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -53,3 +398,103 @@ pub enum LlmSettings {
         env_var_name: String,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocabulary(word: &str) -> Card {
+        serde_json::from_str(&format!(
+            r#"{{"Vocabulary": {{"word": {{"text": "{}"}}, "meaning": {{"text": "-"}},
+                "example_phrases": []}}}}"#,
+            word
+        ))
+        .unwrap()
+    }
+
+    fn kanji(kanji: &str) -> Card {
+        serde_json::from_str(&format!(
+            r#"{{"Kanji": {{"kanji": {{"text": "{}"}}, "description": {{"text": "-"}},
+                "example_words": []}}}}"#,
+            kanji
+        ))
+        .unwrap()
+    }
+
+    fn words(cards: &[Card]) -> Vec<&str> {
+        cards
+            .iter()
+            .map(|card| match card {
+                Card::Vocabulary(vocabulary) => vocabulary.word().text(),
+                Card::Kanji(kanji) => kanji.kanji().text(),
+                _ => "",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_take_cards_by_quota_and_fill_the_rest_in_order() {
+        let cards = vec![
+            vocabulary("猫"),
+            vocabulary("犬"),
+            vocabulary("鳥"),
+            kanji("日"),
+            kanji("月"),
+        ];
+
+        let taken = CardMix::new(1, 1, 0)
+            .unwrap()
+            .take(cards.clone(), 4, |card| card);
+        assert_eq!(words(&taken), vec!["猫", "犬", "日", "月"]);
+
+        let taken = CardMix::new(3, 1, 0)
+            .unwrap()
+            .take(cards.clone(), 3, |card| card);
+        assert_eq!(words(&taken), vec!["猫", "犬", "鳥"]);
+
+        let taken = CardMix::new(0, 1, 0).unwrap().take(cards, 4, |card| card);
+        assert_eq!(words(&taken), vec!["猫", "犬", "日", "月"]);
+    }
+
+    #[test]
+    fn should_reject_invalid_card_mix() {
+        assert!(CardMix::new(0, 0, 0).is_err());
+        assert!(CardMix::new(u32::MAX, 1, 0).is_err());
+        assert!(
+            serde_json::from_str::<CardMix>(r#"{"vocabulary": 0, "kanji": 0, "grammar": 0}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_study_plan_on_deserialize() {
+        let plan = |max_reviews_per_lesson: usize| {
+            format!(
+                r#"{{"new_cards_per_day": 7, "max_reviews_per_lesson": {},
+                    "fixation_cards_limit": 15, "order": "Shuffled",
+                    "card_mix": {{"vocabulary": 1, "kanji": 1, "grammar": 1}}}}"#,
+                max_reviews_per_lesson
+            )
+        };
+
+        assert!(serde_json::from_str::<StudyPlan>(&plan(0)).is_err());
+        let plan = serde_json::from_str::<StudyPlan>(&plan(100)).unwrap();
+        assert!(plan.bury_siblings());
+    }
+
+    #[test]
+    fn should_reject_srs_weights_of_wrong_length() {
+        assert!(SrsParameters::new(vec![0.5; SRS_WEIGHTS_COUNT], 100).is_ok());
+        assert!(SrsParameters::new(vec![0.5; SRS_WEIGHTS_COUNT - 1], 100).is_err());
+        assert!(SrsParameters::new(vec![0.5; SRS_WEIGHTS_COUNT + 1], 100).is_err());
+
+        let parameters = SrsParameters::new(vec![0.5; SRS_WEIGHTS_COUNT], 100).unwrap();
+        let json = serde_json::to_string(&parameters).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SrsParameters>(&json).unwrap(),
+            parameters
+        );
+        let corrupt = json.replacen("0.5,", "", 1);
+        assert!(serde_json::from_str::<SrsParameters>(&corrupt).is_err());
+    }
+}
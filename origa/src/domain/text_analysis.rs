@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use crate::domain::{
+    Card, JapaneseLevel, KANJI_DICTIONARY, KnowledgeSet, OrigaError, VOCABULARY_DICTIONARY,
+    japanese::JapaneseChar, tokenizer::tokenize_text,
+};
+
+/// Доля слов и кандзи текста, которую нужно покрыть уровнем JLPT
+const LEVEL_COVERAGE: f64 = 0.9;
+
+/// Насколько текст понятен пользователю. Слова приводятся к словарной форме
+/// и идут по убыванию числа употреблений в тексте.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextAnalysis {
+    known_words: Vec<String>,
+    learning_words: Vec<String>,
+    new_words: Vec<String>,
+    unknown_words: Vec<String>,
+    coverage: f64,
+    estimated_level: Option<JapaneseLevel>,
+}
+
+impl TextAnalysis {
+    pub fn analyze(text: &str, knowledge_set: &KnowledgeSet) -> Result<Self, OrigaError> {
+        let words = count_words(text)?;
+
+        let vocabulary = word_statuses(knowledge_set.study_cards().values().filter_map(|card| {
+            let memory = card.memory();
+            let status = if memory.is_known_card() {
+                WordStatus::Known
+            } else if memory.is_new() {
+                WordStatus::New
+            } else {
+                WordStatus::Learning
+            };
+            match card.card() {
+                Card::Vocabulary(vocabulary) => Some((vocabulary.word().text(), status)),
+                _ => None,
+            }
+        }));
+
+        let mut known_words = Vec::new();
+        let mut learning_words = Vec::new();
+        let mut new_words = Vec::new();
+        let mut unknown_words = Vec::new();
+        let mut known_occurrences = 0;
+        for (word, occurrences) in &words {
+            match vocabulary.get(word.as_str()) {
+                Some(WordStatus::Known) => {
+                    known_occurrences += occurrences;
+                    known_words.push(word.clone());
+                }
+                Some(WordStatus::Learning) => learning_words.push(word.clone()),
+                Some(WordStatus::New) => new_words.push(word.clone()),
+                None => unknown_words.push(word.clone()),
+            }
+        }
+
+        let total_occurrences: usize = words.iter().map(|(_, occurrences)| occurrences).sum();
+        let coverage = if total_occurrences == 0 {
+            0.0
+        } else {
+            known_occurrences as f64 / total_occurrences as f64 * 100.0
+        };
+
+        let word_levels = words
+            .iter()
+            .filter_map(|(word, _)| VOCABULARY_DICTIONARY.word_level(word))
+            .collect();
+        let mut kanji: Vec<char> = text.chars().filter(|c| c.is_kanji()).collect();
+        kanji.sort();
+        kanji.dedup();
+        let kanji_levels = kanji
+            .iter()
+            .filter_map(|c| KANJI_DICTIONARY.get_kanji_info(&c.to_string()).ok())
+            .map(|info| *info.jlpt())
+            .collect();
+
+        Ok(Self {
+            known_words,
+            learning_words,
+            new_words,
+            unknown_words,
+            coverage,
+            estimated_level: estimate_level(word_levels).max(estimate_level(kanji_levels)),
+        })
+    }
+
+    /// Слова, карточки которых изучены до стабильного уровня
+    pub fn known_words(&self) -> &[String] {
+        &self.known_words
+    }
+
+    /// Слова, карточки которых есть, но еще изучаются
+    pub fn learning_words(&self) -> &[String] {
+        &self.learning_words
+    }
+
+    /// Слова, карточки которых есть, но еще ни разу не повторялись
+    pub fn new_words(&self) -> &[String] {
+        &self.new_words
+    }
+
+    /// Слова без карточек, из них можно сразу создать карточки слов
+    pub fn unknown_words(&self) -> &[String] {
+        &self.unknown_words
+    }
+
+    /// Процент употреблений слов в тексте, которые пользователь знает
+    pub fn coverage(&self) -> f64 {
+        self.coverage
+    }
+
+    /// Уровень JLPT, которого хватает для большей части слов и кандзи текста
+    pub fn estimated_level(&self) -> Option<JapaneseLevel> {
+        self.estimated_level
+    }
+}
+
+/// Насколько пользователь знает слово по его карточке
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum WordStatus {
+    New,
+    Learning,
+    Known,
+}
+
+/// Статус слова по лучшей из его карточек: одно слово может быть в нескольких
+/// карточках, и результат не должен зависеть от их порядка
+fn word_statuses<'a>(
+    cards: impl Iterator<Item = (&'a str, WordStatus)>,
+) -> HashMap<&'a str, WordStatus> {
+    let mut statuses = HashMap::new();
+    for (word, status) in cards {
+        statuses
+            .entry(word)
+            .and_modify(|best: &mut WordStatus| *best = (*best).max(status))
+            .or_insert(status);
+    }
+    statuses
+}
+
+/// Словарные формы слов текста с числом употреблений в порядке убывания
+fn count_words(text: &str) -> Result<Vec<(String, usize)>, OrigaError> {
+    let mut words: Vec<(String, usize)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for token in tokenize_text(text)? {
+        let word = token.orthographic_base_form();
+        if !token.part_of_speech().is_vocabulary_word() || word.is_empty() {
+            continue;
+        }
+
+        match positions.get(word) {
+            Some(&position) => words[position].1 += 1,
+            None => {
+                positions.insert(word.to_string(), words.len());
+                words.push((word.to_string(), 1));
+            }
+        }
+    }
+
+    words.sort_by_key(|(_, occurrences)| std::cmp::Reverse(*occurrences));
+    Ok(words)
+}
+
+/// Самый простой уровень, которого хватает на `LEVEL_COVERAGE` уровней из списка
+fn estimate_level(mut levels: Vec<JapaneseLevel>) -> Option<JapaneseLevel> {
+    if levels.is_empty() {
+        return None;
+    }
+
+    levels.sort();
+    let index = (levels.len() as f64 * LEVEL_COVERAGE).ceil() as usize - 1;
+    Some(levels[index.min(levels.len() - 1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_ignore_rare_hard_levels_when_estimating() {
+        let mut levels = vec![JapaneseLevel::N5; 18];
+        levels.push(JapaneseLevel::N4);
+        levels.push(JapaneseLevel::N1);
+
+        assert_eq!(estimate_level(levels), Some(JapaneseLevel::N5));
+        assert_eq!(
+            estimate_level(vec![JapaneseLevel::N5, JapaneseLevel::N2]),
+            Some(JapaneseLevel::N2)
+        );
+        assert_eq!(estimate_level(vec![]), None);
+    }
+
+    #[test]
+    fn should_take_best_status_of_cards_with_same_word() {
+        let cards = [
+            ("猫", WordStatus::Known),
+            ("猫", WordStatus::Learning),
+            ("犬", WordStatus::New),
+            ("犬", WordStatus::Learning),
+            ("鳥", WordStatus::New),
+        ];
+
+        let statuses = word_statuses(cards.into_iter());
+        let reversed = word_statuses(cards.into_iter().rev());
+
+        assert_eq!(statuses, reversed);
+        assert_eq!(statuses["猫"], WordStatus::Known);
+        assert_eq!(statuses["犬"], WordStatus::Learning);
+        assert_eq!(statuses["鳥"], WordStatus::New);
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::LazyLock;
 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::{OrigaError, filter_japanese_text};
+use crate::domain::{JapaneseChar, OrigaError, filter_japanese_text};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TokenInfo {
@@ -10,6 +10,7 @@ pub struct TokenInfo {
     phonological_base_form: String,
     orthographic_surface_form: String,
     phonological_surface_form: String,
+    lexeme_reading: String,
     part_of_speech: PartOfSpeech,
 }
 
@@ -30,9 +31,57 @@ impl TokenInfo {
         &self.phonological_surface_form
     }
 
+    /// Чтение словарной формы каной, долгие гласные записаны каной, а не знаком ー
+    pub fn lexeme_reading(&self) -> &str {
+        &self.lexeme_reading
+    }
+
     pub fn part_of_speech(&self) -> &PartOfSpeech {
         &self.part_of_speech
     }
+
+    /// Форма слова в тексте каной: слово без кандзи читается так, как написано,
+    /// в произношении остальных долгие гласные берутся из словарного чтения
+    pub fn kana_surface_form(&self) -> String {
+        if !self.orthographic_surface_form.chars().any(|c| c.is_kanji())
+            || self.phonological_surface_form.is_empty()
+        {
+            return self.orthographic_surface_form.clone();
+        }
+
+        restore_long_vowels(
+            &self.phonological_surface_form,
+            &self.phonological_base_form,
+            &self.lexeme_reading,
+        )
+    }
+}
+
+/// Заменяет ー в произношении на кану словарного чтения. Произношение словарной
+/// формы и ее чтение совпадают по длине, поэтому ー в общей с ней части
+/// произношения находится на той же позиции, что и его кана в чтении.
+/// ー без такой каны, например в заимствованных словах, остается
+pub(crate) fn restore_long_vowels(
+    pronunciation: &str,
+    base_pronunciation: &str,
+    base_reading: &str,
+) -> String {
+    let base_pronunciation: Vec<char> = base_pronunciation.chars().collect();
+    let base_reading: Vec<char> = base_reading.chars().collect();
+    let is_aligned = base_pronunciation.len() == base_reading.len();
+
+    let mut is_common_prefix = true;
+    pronunciation
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            is_common_prefix = is_common_prefix && base_pronunciation.get(i) == Some(&c);
+            match base_reading.get(i) {
+                Some(&kana) if c == 'ー' && is_aligned && is_common_prefix => kana,
+                _ => c,
+            }
+        })
+        .collect()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -163,6 +212,7 @@ pub fn tokenize_text(text: &str) -> Result<Vec<TokenInfo>, OrigaError> {
                 .get("phonological_surface_form")
                 .unwrap_or_default()
                 .to_string(),
+            lexeme_reading: token.get("reading").unwrap_or_default().to_string(),
             part_of_speech: token
                 .get("part_of_speech")
                 .unwrap_or_default()
@@ -178,6 +228,30 @@ pub fn tokenize_text(text: &str) -> Result<Vec<TokenInfo>, OrigaError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn should_restore_long_vowels_from_lexeme_reading() {
+        assert_eq!(
+            restore_long_vowels("ガクセー", "ガクセー", "ガクセイ"),
+            "ガクセイ"
+        );
+        assert_eq!(
+            restore_long_vowels("センセー", "センセー", "センセイ"),
+            "センセイ"
+        );
+        assert_eq!(
+            restore_long_vowels("トーキョー", "トーキョー", "トウキョウ"),
+            "トウキョウ"
+        );
+        assert_eq!(
+            restore_long_vowels("オーキク", "オーキー", "オオキイ"),
+            "オオキク"
+        );
+        assert_eq!(
+            restore_long_vowels("コーヒー", "コーヒー", "コーヒー"),
+            "コーヒー"
+        );
+    }
+
     #[test]
     fn should_return_base_form_for_verb() {
         let tokens = tokenize_text("食べます").unwrap();
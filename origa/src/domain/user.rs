@@ -3,8 +3,8 @@ use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
 use crate::domain::{
-    Card, JapaneseLevel, KnowledgeSet, MemoryState, NativeLanguage, OrigaError, Rating, StudyCard,
-    UserSettings,
+    Answer, Card, ExamplePhrase, JapaneseLevel, KnowledgeSet, MemoryState, NativeLanguage,
+    OrigaError, Question, ReviewLog, StudyCard, UserSettings,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,15 +64,18 @@ impl User {
     pub fn rate_card(
         &mut self,
         card_id: Ulid,
-        rating: Rating,
-        interval: Duration,
         memory_state: MemoryState,
+        review: ReviewLog,
     ) -> Result<(), OrigaError> {
         self.knowledge_set
-            .rate_card(card_id, rating, interval, memory_state)?;
+            .rate_card(card_id, memory_state, review)?;
         Ok(())
     }
 
+    pub fn undo_last_rating(&mut self) -> Result<Ulid, OrigaError> {
+        self.knowledge_set.undo_last_rating()
+    }
+
     pub fn add_lesson_duration(&mut self, lesson_duration: Duration) {
         self.knowledge_set.add_lesson_duration(lesson_duration);
     }
@@ -84,4 +87,32 @@ impl User {
     pub fn create_card(&mut self, card: Card) -> Result<StudyCard, OrigaError> {
         self.knowledge_set.create_card(card)
     }
+
+    pub fn edit_card(
+        &mut self,
+        card_id: Ulid,
+        question: Option<Question>,
+        answer: Option<Answer>,
+        example_phrases: Option<Vec<ExamplePhrase>>,
+    ) -> Result<StudyCard, OrigaError> {
+        self.knowledge_set.edit_card(
+            card_id,
+            question,
+            answer,
+            example_phrases,
+            &self.native_language,
+        )
+    }
+
+    pub fn suspend_card(&mut self, card_id: Ulid) -> Result<(), OrigaError> {
+        self.knowledge_set.suspend_card(card_id)
+    }
+
+    pub fn unsuspend_card(&mut self, card_id: Ulid) -> Result<(), OrigaError> {
+        self.knowledge_set.unsuspend_card(card_id)
+    }
+
+    pub fn reset_card(&mut self, card_id: Ulid) -> Result<(), OrigaError> {
+        self.knowledge_set.reset_card(card_id)
+    }
 }
@@ -0,0 +1,411 @@
+use crate::application::NextReview;
+use crate::application::RateMode;
+use crate::application::SrsService;
+use crate::application::WorkloadDay;
+use crate::domain::OrigaError;
+use crate::domain::Rating;
+use crate::domain::{
+    Difficulty, LearningState, MemoryHistory, MemoryState, SrsParameters, Stability,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rs_fsrs::{Card as FsrsCard, FSRS, Parameters, Rating as FsrsRating, State as FsrsState};
+
+use super::optimizer::FsrsOptimizer;
+
+/// Число прогонов моделирования, по которым усредняется прогноз
+const SIMULATION_RUNS: u64 = 20;
+
+pub struct FsrsSrsService {
+    short_term_parameters: Parameters,
+    long_term_parameters: Parameters,
+}
+
+impl FsrsSrsService {
+    pub fn new() -> Result<Self, OrigaError> {
+        let short_term_parameters = Parameters {
+            request_retention: 0.95,
+            maximum_interval: 1, // 1 day for short-term learning sessions
+            enable_fuzz: true,
+            enable_short_term: false,
+            ..Default::default()
+        };
+
+        let long_term_parameters = Parameters {
+            enable_fuzz: true,
+            enable_short_term: true,
+            ..Default::default()
+        };
+
+        Ok(Self {
+            long_term_parameters,
+            short_term_parameters,
+        })
+    }
+
+    fn fsrs(
+        &self,
+        mode: &RateMode,
+        srs_parameters: Option<&SrsParameters>,
+    ) -> Result<FSRS, OrigaError> {
+        let parameters = match mode {
+            RateMode::FixationLesson => self.short_term_parameters.clone(),
+            RateMode::StandardLesson => self.long_term_parameters.clone(),
+        };
+
+        with_weights(parameters, srs_parameters)
+    }
+}
+
+/// Планировщик с весами пользователя. Веса, которые не подходят модели,
+/// считаются ошибкой, а не заменяются весами по умолчанию
+fn with_weights(
+    mut parameters: Parameters,
+    srs_parameters: Option<&SrsParameters>,
+) -> Result<FSRS, OrigaError> {
+    if let Some(srs_parameters) = srs_parameters {
+        parameters.w =
+            srs_parameters
+                .weights()
+                .try_into()
+                .map_err(|_| OrigaError::InvalidValues {
+                    reason: format!(
+                        "Expected {} SRS weights, got {}",
+                        parameters.w.len(),
+                        srs_parameters.weights().len()
+                    ),
+                })?;
+    }
+
+    Ok(FSRS::new(parameters))
+}
+
+fn to_fsrs_state(state: LearningState) -> FsrsState {
+    match state {
+        LearningState::New => FsrsState::New,
+        LearningState::Learning => FsrsState::Learning,
+        LearningState::Review => FsrsState::Review,
+        LearningState::Relearning => FsrsState::Relearning,
+    }
+}
+
+fn from_fsrs_state(state: FsrsState) -> LearningState {
+    match state {
+        FsrsState::New => LearningState::New,
+        FsrsState::Learning => LearningState::Learning,
+        FsrsState::Review => LearningState::Review,
+        FsrsState::Relearning => LearningState::Relearning,
+    }
+}
+
+fn to_fsrs_card(memory_history: &MemoryHistory, now: DateTime<Utc>) -> FsrsCard {
+    let Some(memory_state) = memory_history.memory_state() else {
+        return FsrsCard::new();
+    };
+
+    let last_review_date = memory_history
+        .reviews()
+        .back()
+        .map(|review| review.timestamp())
+        .unwrap_or(now);
+
+    let elapsed_days = now
+        .signed_duration_since(last_review_date)
+        .num_days()
+        .max(0);
+
+    let scheduled_days = memory_state
+        .next_review_date()
+        .signed_duration_since(last_review_date)
+        .num_days()
+        .max(0);
+
+    FsrsCard {
+        due: *memory_state.next_review_date(),
+        stability: memory_state.stability().value(),
+        difficulty: memory_state.difficulty().value(),
+        elapsed_days,
+        scheduled_days,
+        reps: memory_state.reps() as i32,
+        lapses: memory_state.lapses() as i32,
+        state: to_fsrs_state(memory_state.learning_state()),
+        last_review: last_review_date,
+    }
+}
+
+pub(super) fn to_fsrs_rating(rating: Rating) -> FsrsRating {
+    match rating {
+        Rating::Again => FsrsRating::Again,
+        Rating::Hard => FsrsRating::Hard,
+        Rating::Good => FsrsRating::Good,
+        Rating::Easy => FsrsRating::Easy,
+    }
+}
+
+#[async_trait(?Send)]
+impl SrsService for FsrsSrsService {
+    async fn rate(
+        &self,
+        mode: RateMode,
+        rating: Rating,
+        memory_history: &MemoryHistory,
+        parameters: Option<&SrsParameters>,
+    ) -> Result<NextReview, OrigaError> {
+        let now = Utc::now();
+        let card = to_fsrs_card(memory_history, now);
+
+        let scheduling_info = self
+            .fsrs(&mode, parameters)?
+            .next(card, now, to_fsrs_rating(rating));
+
+        let next_review_date = scheduling_info.card.due;
+
+        let interval = next_review_date.signed_duration_since(now);
+        let interval = if interval < Duration::zero() || rating == Rating::Again {
+            Duration::zero()
+        } else {
+            interval
+        };
+
+        let stability = Stability::new(scheduling_info.card.stability)?;
+        let difficulty = Difficulty::new(scheduling_info.card.difficulty)?;
+        let memory_state = MemoryState::new(
+            stability,
+            difficulty,
+            next_review_date,
+            from_fsrs_state(scheduling_info.card.state),
+            scheduling_info.card.reps.max(0) as u32,
+            scheduling_info.card.lapses.max(0) as u32,
+        );
+
+        Ok(NextReview {
+            interval,
+            memory_state,
+        })
+    }
+
+    async fn optimize(
+        &self,
+        memory_histories: &[&MemoryHistory],
+    ) -> Result<SrsParameters, OrigaError> {
+        let optimizer = FsrsOptimizer::new(memory_histories);
+        let weights = optimizer.optimize(self.long_term_parameters.w)?;
+
+        SrsParameters::new(weights.to_vec(), optimizer.reviews_count())
+    }
+
+    async fn simulate(
+        &self,
+        memory_histories: &[&MemoryHistory],
+        new_cards: usize,
+        new_cards_per_day: usize,
+        days: usize,
+        parameters: Option<&SrsParameters>,
+    ) -> Result<Vec<WorkloadDay>, OrigaError> {
+        self.simulate_from(
+            memory_histories,
+            new_cards,
+            new_cards_per_day,
+            days,
+            parameters,
+            Utc::now(),
+        )
+    }
+
+    async fn project(
+        &self,
+        memory_histories: &[&MemoryHistory],
+        new_cards: usize,
+        new_cards_per_day: usize,
+        days: usize,
+        parameters: Option<&SrsParameters>,
+    ) -> Result<Vec<WorkloadDay>, OrigaError> {
+        Ok(forecast(
+            &self.forecast_fsrs(parameters)?,
+            memory_histories,
+            new_cards,
+            new_cards_per_day,
+            days,
+            Utc::now(),
+            |_, _| FsrsRating::Good,
+        ))
+    }
+}
+
+impl FsrsSrsService {
+    /// Планировщик для прогноза: без случайного разброса интервалов,
+    /// чтобы одинаковые данные давали одинаковый прогноз
+    fn forecast_fsrs(&self, srs_parameters: Option<&SrsParameters>) -> Result<FSRS, OrigaError> {
+        let parameters = Parameters {
+            enable_fuzz: false,
+            ..self.long_term_parameters.clone()
+        };
+
+        with_weights(parameters, srs_parameters)
+    }
+
+    fn simulate_from(
+        &self,
+        memory_histories: &[&MemoryHistory],
+        new_cards: usize,
+        new_cards_per_day: usize,
+        days: usize,
+        parameters: Option<&SrsParameters>,
+        start: DateTime<Utc>,
+    ) -> Result<Vec<WorkloadDay>, OrigaError> {
+        let fsrs = self.forecast_fsrs(parameters)?;
+
+        let runs: Vec<_> = (0..SIMULATION_RUNS)
+            .map(|seed| {
+                let mut rng = StdRng::seed_from_u64(seed);
+                forecast(
+                    &fsrs,
+                    memory_histories,
+                    new_cards,
+                    new_cards_per_day,
+                    days,
+                    start,
+                    |card, now| {
+                        if rng.random_bool(card.get_retrievability(now).clamp(0.0, 1.0)) {
+                            FsrsRating::Good
+                        } else {
+                            FsrsRating::Again
+                        }
+                    },
+                )
+            })
+            .collect();
+
+        Ok(average_runs(runs))
+    }
+}
+
+/// Прогон повторений по дням: карточка повторяется в день, на который она назначена,
+/// оценку выбирает `rate`
+fn forecast(
+    fsrs: &FSRS,
+    memory_histories: &[&MemoryHistory],
+    new_cards: usize,
+    new_cards_per_day: usize,
+    days: usize,
+    start: DateTime<Utc>,
+    mut rate: impl FnMut(&FsrsCard, DateTime<Utc>) -> FsrsRating,
+) -> Vec<WorkloadDay> {
+    let mut cards: Vec<_> = memory_histories
+        .iter()
+        .filter(|history| !history.is_new())
+        .map(|history| to_fsrs_card(history, start))
+        .collect();
+    let mut remaining_new_cards = new_cards;
+    let mut forecast = Vec::with_capacity(days);
+
+    for day in 0..days {
+        let now = start + Duration::days(day as i64);
+        let date = now.date_naive();
+
+        let mut reviews = 0;
+        for card in cards.iter_mut() {
+            if card.due.date_naive() > date {
+                continue;
+            }
+
+            let rating = rate(card, now);
+            *card = fsrs.next(card.clone(), now, rating).card;
+            reviews += 1;
+        }
+
+        let introduced = new_cards_per_day.min(remaining_new_cards);
+        remaining_new_cards -= introduced;
+        cards.extend(
+            (0..introduced).map(|_| fsrs.next(FsrsCard::new(), now, FsrsRating::Good).card),
+        );
+
+        forecast.push(WorkloadDay {
+            date,
+            reviews,
+            new_cards: introduced,
+        });
+    }
+
+    forecast
+}
+
+/// Среднее число повторений по дням нескольких прогонов
+fn average_runs(runs: Vec<Vec<WorkloadDay>>) -> Vec<WorkloadDay> {
+    let count = runs.len().max(1) as f64;
+    let mut runs = runs.into_iter();
+    let Some(mut average) = runs.next() else {
+        return Vec::new();
+    };
+
+    let mut totals: Vec<usize> = average.iter().map(|day| day.reviews).collect();
+    for run in runs {
+        for (total, day) in totals.iter_mut().zip(run) {
+            *total += day.reviews;
+        }
+    }
+    for (day, total) in average.iter_mut().zip(totals) {
+        day.reviews = (total as f64 / count).round() as usize;
+    }
+
+    average
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{CardVariant, ReviewLog, ReviewMode};
+
+    fn review_history(due_in_days: i64, stability: f64) -> MemoryHistory {
+        let mut history = MemoryHistory::new();
+        history.add_review(
+            MemoryState::new(
+                Stability::new(stability).unwrap(),
+                Difficulty::new(5.0).unwrap(),
+                Utc::now() + Duration::days(due_in_days),
+                LearningState::Review,
+                3,
+                0,
+            ),
+            ReviewLog::new(
+                Rating::Good,
+                Duration::days(due_in_days),
+                ReviewMode::Standard,
+                CardVariant::Original,
+                None,
+            ),
+        );
+        history
+    }
+
+    #[tokio::test]
+    async fn should_project_reviews_on_due_dates() {
+        let service = FsrsSrsService::new().unwrap();
+        let history = review_history(3, 10.0);
+
+        let forecast = service.project(&[&history], 0, 0, 10, None).await.unwrap();
+        let reviews: Vec<_> = forecast.iter().map(|day| day.reviews).collect();
+
+        assert_eq!(reviews, vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn should_simulate_same_forecast_for_same_cards() {
+        let service = FsrsSrsService::new().unwrap();
+        let histories: Vec<_> = (1..20).map(|day| review_history(day % 5, 2.0)).collect();
+        let histories: Vec<_> = histories.iter().collect();
+        let start = Utc::now();
+
+        let first = service
+            .simulate_from(&histories, 10, 2, 30, None, start)
+            .unwrap();
+        let second = service
+            .simulate_from(&histories, 10, 2, 30, None, start)
+            .unwrap();
+
+        assert_eq!(first.len(), 30);
+        assert_eq!(first, second);
+        assert_eq!(first.iter().map(|day| day.new_cards).sum::<usize>(), 10);
+    }
+}
@@ -0,0 +1,4 @@
+mod fsrs_service;
+mod optimizer;
+
+pub use fsrs_service::FsrsSrsService;
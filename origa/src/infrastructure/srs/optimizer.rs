@@ -0,0 +1,322 @@
+use chrono::{DateTime, Utc};
+use rs_fsrs::Parameters;
+
+use crate::domain::{MemoryHistory, OrigaError, Rating, ReviewMode};
+
+use super::fsrs_service::to_fsrs_rating;
+
+type Weights = [f64; 19];
+
+const MIN_REVIEWS_FOR_OPTIMIZATION: usize = 200;
+const MAX_ITERATIONS: usize = 150;
+const LEARNING_RATE: f64 = 0.01;
+const GRADIENT_STEP: f64 = 1e-4;
+const ADAM_BETA_1: f64 = 0.9;
+const ADAM_BETA_2: f64 = 0.999;
+const ADAM_EPSILON: f64 = 1e-8;
+const MIN_STABILITY: f64 = 0.01;
+const MAX_STABILITY: f64 = 36500.0;
+
+/// Допустимые границы весов FSRS, за которые оптимизатор не выходит
+const WEIGHT_BOUNDS: [(f64, f64); 19] = [
+    (0.01, 100.0),
+    (0.01, 100.0),
+    (0.01, 100.0),
+    (0.01, 100.0),
+    (1.0, 10.0),
+    (0.001, 4.0),
+    (0.001, 4.0),
+    (0.001, 0.75),
+    (0.0, 4.5),
+    (0.0, 0.8),
+    (0.001, 3.5),
+    (0.001, 5.0),
+    (0.001, 0.25),
+    (0.001, 0.9),
+    (0.0, 4.0),
+    (0.0, 1.0),
+    (1.0, 6.0),
+    (0.0, 2.0),
+    (0.0, 2.0),
+];
+
+#[derive(Debug, Clone, Copy)]
+struct ReviewItem {
+    rating: Rating,
+    timestamp: DateTime<Utc>,
+}
+
+/// Подбирает веса FSRS под историю повторений пользователя, минимизируя
+/// log loss между предсказанной вероятностью вспомнить карту и фактической оценкой.
+/// Повторения из уроков закрепления не учитываются: они идут по краткосрочным параметрам.
+pub(super) struct FsrsOptimizer {
+    histories: Vec<Vec<ReviewItem>>,
+    reviews_count: usize,
+}
+
+impl FsrsOptimizer {
+    pub fn new(memory_histories: &[&MemoryHistory]) -> Self {
+        let histories = memory_histories
+            .iter()
+            .map(|history| {
+                history
+                    .reviews()
+                    .iter()
+                    .filter(|review| review.mode() != ReviewMode::Fixation)
+                    .map(|review| ReviewItem {
+                        rating: review.rating(),
+                        timestamp: review.timestamp(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Self::from_histories(histories)
+    }
+
+    fn from_histories(mut histories: Vec<Vec<ReviewItem>>) -> Self {
+        histories.retain(|reviews| reviews.len() > 1);
+        for reviews in histories.iter_mut() {
+            reviews.sort_by_key(|review| review.timestamp);
+        }
+
+        let reviews_count = histories
+            .iter()
+            .flat_map(|reviews| reviews.windows(2))
+            .filter(|pair| elapsed_days(&pair[0], &pair[1]) >= 1)
+            .count();
+
+        Self {
+            histories,
+            reviews_count,
+        }
+    }
+
+    /// Количество повторений, по которым можно оценить забывание
+    pub fn reviews_count(&self) -> usize {
+        self.reviews_count
+    }
+
+    pub fn optimize(&self, initial_weights: Weights) -> Result<Weights, OrigaError> {
+        if self.reviews_count < MIN_REVIEWS_FOR_OPTIMIZATION {
+            return Err(OrigaError::SrsCalculationFailed {
+                reason: format!(
+                    "Not enough reviews to optimize parameters: {} of {}",
+                    self.reviews_count, MIN_REVIEWS_FOR_OPTIMIZATION
+                ),
+            });
+        }
+
+        let mut weights = clamp_weights(initial_weights);
+        let mut best_weights = weights;
+        let mut best_loss = self.loss(&weights);
+
+        let mut first_moment = [0.0; 19];
+        let mut second_moment = [0.0; 19];
+
+        for iteration in 1..=MAX_ITERATIONS {
+            let gradient = self.gradient(&weights);
+            let learning_rate =
+                LEARNING_RATE * (1.0 - (iteration - 1) as f64 / MAX_ITERATIONS as f64);
+
+            for i in 0..weights.len() {
+                first_moment[i] = ADAM_BETA_1 * first_moment[i] + (1.0 - ADAM_BETA_1) * gradient[i];
+                second_moment[i] =
+                    ADAM_BETA_2 * second_moment[i] + (1.0 - ADAM_BETA_2) * gradient[i].powi(2);
+
+                let corrected_first = first_moment[i] / (1.0 - ADAM_BETA_1.powi(iteration as i32));
+                let corrected_second =
+                    second_moment[i] / (1.0 - ADAM_BETA_2.powi(iteration as i32));
+
+                let (low, high) = WEIGHT_BOUNDS[i];
+                weights[i] -= learning_rate * (high - low) * corrected_first
+                    / (corrected_second.sqrt() + ADAM_EPSILON);
+            }
+
+            weights = clamp_weights(weights);
+
+            let loss = self.loss(&weights);
+            if loss < best_loss {
+                best_loss = loss;
+                best_weights = weights;
+            }
+        }
+
+        Ok(best_weights)
+    }
+
+    fn gradient(&self, weights: &Weights) -> Weights {
+        let base_loss = self.loss(weights);
+        let mut gradient = [0.0; 19];
+
+        for (i, value) in gradient.iter_mut().enumerate() {
+            let (low, high) = WEIGHT_BOUNDS[i];
+            let step = GRADIENT_STEP * (high - low);
+
+            let mut shifted = *weights;
+            shifted[i] = (shifted[i] + step).min(high);
+            let delta = shifted[i] - weights[i];
+
+            *value = if delta > 0.0 {
+                (self.loss(&shifted) - base_loss) / delta
+            } else {
+                shifted[i] = weights[i] - step;
+                (base_loss - self.loss(&shifted)) / step
+            };
+        }
+
+        gradient
+    }
+
+    fn loss(&self, weights: &Weights) -> f64 {
+        let parameters = Parameters {
+            w: *weights,
+            ..Default::default()
+        };
+
+        let mut total_loss = 0.0;
+        let mut total_count = 0;
+
+        for reviews in &self.histories {
+            let first = reviews[0];
+            let first_rating = to_fsrs_rating(first.rating);
+            let mut stability = parameters.init_stability(first_rating);
+            let mut difficulty = parameters.init_difficulty(first_rating);
+
+            for pair in reviews.windows(2) {
+                let review = pair[1];
+                let rating = to_fsrs_rating(review.rating);
+                let elapsed_days = elapsed_days(&pair[0], &review);
+
+                stability = if elapsed_days < 1 {
+                    parameters.short_term_stability(stability, rating)
+                } else {
+                    let retrievability =
+                        Parameters::forgetting_curve(elapsed_days as f64, stability)
+                            .clamp(1e-6, 1.0 - 1e-6);
+
+                    total_loss += if review.rating == Rating::Again {
+                        -(1.0 - retrievability).ln()
+                    } else {
+                        -retrievability.ln()
+                    };
+                    total_count += 1;
+
+                    if review.rating == Rating::Again {
+                        parameters.next_forget_stability(difficulty, stability, retrievability)
+                    } else {
+                        parameters.next_recall_stability(
+                            difficulty,
+                            stability,
+                            retrievability,
+                            rating,
+                        )
+                    }
+                }
+                .clamp(MIN_STABILITY, MAX_STABILITY);
+
+                difficulty = parameters.next_difficulty(difficulty, rating);
+            }
+        }
+
+        if total_count == 0 {
+            0.0
+        } else {
+            total_loss / total_count as f64
+        }
+    }
+}
+
+fn elapsed_days(previous: &ReviewItem, current: &ReviewItem) -> i64 {
+    current
+        .timestamp
+        .signed_duration_since(previous.timestamp)
+        .num_days()
+}
+
+fn clamp_weights(mut weights: Weights) -> Weights {
+    for (weight, (low, high)) in weights.iter_mut().zip(WEIGHT_BOUNDS) {
+        *weight = weight.clamp(low, high);
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn history(reviews: &[(Rating, i64)]) -> Vec<ReviewItem> {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+        reviews
+            .iter()
+            .map(|(rating, day)| ReviewItem {
+                rating: *rating,
+                timestamp: start + Duration::days(*day),
+            })
+            .collect()
+    }
+
+    fn forgetful_histories() -> Vec<Vec<ReviewItem>> {
+        (0..60)
+            .map(|i| {
+                if i % 3 == 0 {
+                    history(&[
+                        (Rating::Good, 0),
+                        (Rating::Again, 3),
+                        (Rating::Good, 4),
+                        (Rating::Again, 12),
+                        (Rating::Good, 13),
+                    ])
+                } else {
+                    history(&[
+                        (Rating::Good, 0),
+                        (Rating::Again, 2),
+                        (Rating::Hard, 3),
+                        (Rating::Good, 6),
+                        (Rating::Again, 20),
+                    ])
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_not_increase_loss_after_optimization() {
+        let optimizer = FsrsOptimizer::from_histories(forgetful_histories());
+        let default_weights = Parameters::default().w;
+
+        let optimized_weights = optimizer.optimize(default_weights).unwrap();
+
+        assert!(optimizer.loss(&optimized_weights) < optimizer.loss(&default_weights));
+    }
+
+    #[test]
+    fn should_keep_weights_within_bounds() {
+        let optimizer = FsrsOptimizer::from_histories(forgetful_histories());
+
+        let optimized_weights = optimizer.optimize(Parameters::default().w).unwrap();
+
+        for (weight, (low, high)) in optimized_weights.iter().zip(WEIGHT_BOUNDS) {
+            assert!(*weight >= low && *weight <= high);
+        }
+    }
+
+    #[test]
+    fn should_count_only_reviews_after_a_day() {
+        let optimizer = FsrsOptimizer::from_histories(vec![
+            history(&[(Rating::Good, 0), (Rating::Good, 0), (Rating::Good, 2)]),
+            history(&[(Rating::Good, 0)]),
+        ]);
+
+        assert_eq!(optimizer.reviews_count(), 1);
+    }
+
+    #[test]
+    fn should_fail_when_not_enough_reviews() {
+        let optimizer =
+            FsrsOptimizer::from_histories(vec![history(&[(Rating::Good, 0), (Rating::Good, 2)])]);
+
+        assert!(optimizer.optimize(Parameters::default().w).is_err());
+    }
+}
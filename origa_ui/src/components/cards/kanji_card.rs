@@ -3,6 +3,17 @@ use crate::components::cards::vocab_card::StatusBadge;
 use leptos::prelude::*;
 use origa::domain::JapaneseLevel;
 
+/// Число черт с формой слова по числу: 1 черта, 3 черты, 12 черт
+pub fn stroke_count_label(count: u8) -> String {
+    let word = match (count % 10, count % 100) {
+        (_, 11..=14) => "черт",
+        (1, _) => "черта",
+        (2..=4, _) => "черты",
+        _ => "черт",
+    };
+    format!("{} {}", count, word)
+}
+
 #[component]
 pub fn KanjiCard(
     card: KanjiCardData,
@@ -45,7 +56,7 @@ pub fn KanjiCard(
             <div class="kanji-header">
                 <div class="kanji-character-section">
                     <span class="kanji-character">{card.character}</span>
-                    <span class="kanji-stroke-count">{card.stroke_count}черт</span>
+                    <span class="kanji-stroke-count">{stroke_count_label(card.stroke_count)}</span>
                 </div>
 
                 <div class="kanji-meta">
@@ -72,33 +83,38 @@ pub fn KanjiCard(
                 </div>
             </div>
 
-            <div class="kanji-readings">
-                <div class="reading-section">
-                    <h5 class="reading-title">Onyomi:</h5>
-                    <div class="readings-list onyomi">
-                        {card
-                            .onyomi
-                            .iter()
-                            .map(|reading| {
-                                view! { <span class="reading-item">{reading.clone()}</span> }
-                            })
-                            .collect_view()}
-                    </div>
-                </div>
+            {(!card.onyomi.is_empty() || !card.kunyomi.is_empty())
+                .then(|| {
+                    view! {
+                        <div class="kanji-readings">
+                            <div class="reading-section">
+                                <h5 class="reading-title">Onyomi:</h5>
+                                <div class="readings-list onyomi">
+                                    {card
+                                        .onyomi
+                                        .iter()
+                                        .map(|reading| {
+                                            view! { <span class="reading-item">{reading.clone()}</span> }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </div>
 
-                <div class="reading-section">
-                    <h5 class="reading-title">Kunyomi:</h5>
-                    <div class="readings-list kunyomi">
-                        {card
-                            .kunyomi
-                            .iter()
-                            .map(|reading| {
-                                view! { <span class="reading-item">{reading.clone()}</span> }
-                            })
-                            .collect_view()}
-                    </div>
-                </div>
-            </div>
+                            <div class="reading-section">
+                                <h5 class="reading-title">Kunyomi:</h5>
+                                <div class="readings-list kunyomi">
+                                    {card
+                                        .kunyomi
+                                        .iter()
+                                        .map(|reading| {
+                                            view! { <span class="reading-item">{reading.clone()}</span> }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </div>
+                        </div>
+                    }
+                })}
 
             <div class="kanji-radicals">
                 <h5 class="radicals-title">Радикалы:</h5>
@@ -1,4 +1,5 @@
 use crate::components::cards::base_card::BaseCard;
+use crate::components::cards::kanji_card::stroke_count_label;
 use crate::components::cards::vocab_card::StatusBadge;
 use leptos::prelude::*;
 use origa::domain::JapaneseLevel;
@@ -75,7 +76,7 @@ pub fn KanjiDetail(
                             {kanji.jlpt_level.to_string()}
                         </div>
                         <div class="stroke-info">
-                            <span class="stroke-count">{kanji.stroke_count}черт</span>
+                            <span class="stroke-count">{stroke_count_label(kanji.stroke_count)}</span>
                             <span class="grade-level">{kanji.grade_level}</span>
                         </div>
                     </div>
@@ -103,56 +104,61 @@ pub fn KanjiDetail(
             </BaseCard>
 
             // Readings section
-            <div class="readings-grid">
-                <BaseCard>
-                    <h3 class="subsection-heading">Onyomi (китайское чтение)</h3>
-                    <div class="readings-detail">
-                        {kanji
-                            .onyomi
-                            .iter()
-                            .enumerate()
-                            .map(|(i, reading)| {
-                                let romaji = reading.romaji.clone();
-                                let hiragana = reading.hiragana.clone();
-                                view! {
-                                    <div class="reading-detail-item">
-                                        <span class="reading-number">{i + 1}.</span>
-                                        <span class="reading-romaji">{romaji}</span>
-                                        <span class="reading-japanese">{hiragana}</span>
-                                    </div>
-                                }
-                            })
-                            .collect_view()}
-                    </div>
-                </BaseCard>
+            {(!kanji.onyomi.is_empty() || !kanji.kunyomi.is_empty())
+                .then(|| {
+                    view! {
+                        <div class="readings-grid">
+                            <BaseCard>
+                                <h3 class="subsection-heading">Onyomi (китайское чтение)</h3>
+                                <div class="readings-detail">
+                                    {kanji
+                                        .onyomi
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(i, reading)| {
+                                            let romaji = reading.romaji.clone();
+                                            let hiragana = reading.hiragana.clone();
+                                            view! {
+                                                <div class="reading-detail-item">
+                                                    <span class="reading-number">{i + 1}.</span>
+                                                    <span class="reading-romaji">{romaji}</span>
+                                                    <span class="reading-japanese">{hiragana}</span>
+                                                </div>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </BaseCard>
 
-                <BaseCard>
-                    <h3 class="subsection-heading">Kunyomi (японское чтение)</h3>
-                    <div class="readings-detail">
-                        {kanji
-                            .kunyomi
-                            .iter()
-                            .enumerate()
-                            .map(|(i, reading)| {
-                                let romaji = reading.romaji.clone();
-                                let hiragana = reading.hiragana.clone();
-                                let okurigana = reading.okurigana.clone();
-                                view! {
-                                    <div class="reading-detail-item">
-                                        <span class="reading-number">{i + 1}.</span>
-                                        <span class="reading-romaji">{romaji}</span>
-                                        <span class="reading-japanese">{hiragana}</span>
-                                        {okurigana
-                                            .map(|ok| {
-                                                view! { <span class="reading-okurigana">{ok}</span> }
-                                            })}
-                                    </div>
-                                }
-                            })
-                            .collect_view()}
-                    </div>
-                </BaseCard>
-            </div>
+                            <BaseCard>
+                                <h3 class="subsection-heading">Kunyomi (японское чтение)</h3>
+                                <div class="readings-detail">
+                                    {kanji
+                                        .kunyomi
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(i, reading)| {
+                                            let romaji = reading.romaji.clone();
+                                            let hiragana = reading.hiragana.clone();
+                                            let okurigana = reading.okurigana.clone();
+                                            view! {
+                                                <div class="reading-detail-item">
+                                                    <span class="reading-number">{i + 1}.</span>
+                                                    <span class="reading-romaji">{romaji}</span>
+                                                    <span class="reading-japanese">{hiragana}</span>
+                                                    {okurigana
+                                                        .map(|ok| {
+                                                            view! { <span class="reading-okurigana">{ok}</span> }
+                                                        })}
+                                                </div>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </BaseCard>
+                        </div>
+                    }
+                })}
 
             // Radicals section
             <BaseCard>
@@ -173,7 +179,7 @@ pub fn KanjiDetail(
                                         <span class="radical-meaning">{meaning}</span>
                                     </div>
                                     <div class="radical-meta">
-                                        <span class="radical-stroke">{stroke_count}черт</span>
+                                        <span class="radical-stroke">{stroke_count_label(stroke_count)}</span>
                                         <span class="radical-position">{position}</span>
                                     </div>
                                 </div>
@@ -1,5 +1,7 @@
+use crate::components::cards::kanji_card::stroke_count_label;
 use leptos::prelude::*;
 use leptos_use::use_timeout_fn;
+use origa::domain::PitchAccent;
 
 #[component]
 pub fn FlashCard(
@@ -32,7 +34,10 @@ pub fn FlashCard(
                                 Some(wrapper) => match &wrapper.card {
                                     StudyCard::Vocab(_) => "📚 Слово",
                                     StudyCard::Kanji(_) => "🈁 Кандзи",
+                                    StudyCard::KanjiWriting(_) => "🖌 Написание",
                                     StudyCard::Grammar(_) => "📝 Грамматика",
+                                    StudyCard::Sentence(_) => "💬 Предложение",
+                                    StudyCard::Cloze(_) => "✏️ Пропуск",
                                 },
                                 None => "",
                             };
@@ -83,9 +88,16 @@ fn render_card_content(card: &StudyCard) -> leptos::prelude::AnyView {
     match card {
         StudyCard::Vocab(vocab) => view! { <VocabCardContent vocab=vocab.clone() /> }.into_any(),
         StudyCard::Kanji(kanji) => view! { <KanjiCardContent kanji=kanji.clone() /> }.into_any(),
+        StudyCard::KanjiWriting(writing) => {
+            view! { <KanjiWritingCardContent writing=writing.clone() /> }.into_any()
+        }
         StudyCard::Grammar(grammar) => {
             view! { <GrammarCardContent grammar=grammar.clone() /> }.into_any()
         }
+        StudyCard::Sentence(sentence) => {
+            view! { <SentenceCardContent sentence=sentence.clone() /> }.into_any()
+        }
+        StudyCard::Cloze(cloze) => view! { <ClozeCardContent cloze=cloze.clone() /> }.into_any(),
     }
 }
 
@@ -93,9 +105,16 @@ fn render_answer_content(card: &StudyCard) -> leptos::prelude::AnyView {
     match card {
         StudyCard::Vocab(vocab) => view! { <VocabAnswerContent vocab=vocab.clone() /> }.into_any(),
         StudyCard::Kanji(kanji) => view! { <KanjiAnswerContent kanji=kanji.clone() /> }.into_any(),
+        StudyCard::KanjiWriting(writing) => {
+            view! { <KanjiWritingAnswerContent writing=writing.clone() /> }.into_any()
+        }
         StudyCard::Grammar(grammar) => {
             view! { <GrammarAnswerContent grammar=grammar.clone() /> }.into_any()
         }
+        StudyCard::Sentence(sentence) => {
+            view! { <SentenceAnswerContent sentence=sentence.clone() /> }.into_any()
+        }
+        StudyCard::Cloze(cloze) => view! { <ClozeAnswerContent cloze=cloze.clone() /> }.into_any(),
     }
 }
 
@@ -160,6 +179,11 @@ fn VocabAnswerContent(vocab: VocabCard) -> impl IntoView {
             <div class="answer-header">
                 <h4 class="answer-title">{vocab.japanese}</h4>
                 <span class="answer-reading">{vocab.reading}</span>
+                {vocab
+                    .pitch_accents
+                    .into_iter()
+                    .map(|accent| view! { <PitchAccentReading accent=accent /> })
+                    .collect_view()}
             </div>
 
             <div class="answer-translation">
@@ -205,12 +229,41 @@ fn VocabAnswerContent(vocab: VocabCard) -> impl IntoView {
     }
 }
 
+/// Чтение с линией тона над высокими морами и засечкой на месте падения
+#[component]
+fn PitchAccentReading(accent: PitchAccent) -> impl IntoView {
+    let particle_class = if accent.is_particle_high() {
+        "pitch-mora pitch-particle high"
+    } else {
+        "pitch-mora pitch-particle low"
+    };
+
+    view! {
+        <span class="pitch-accent">
+            {accent
+                .morae()
+                .into_iter()
+                .map(|mora| {
+                    let class = match (mora.is_high(), mora.is_downstep()) {
+                        (true, true) => "pitch-mora high downstep",
+                        (true, false) => "pitch-mora high",
+                        (false, _) => "pitch-mora low",
+                    };
+                    view! { <span class=class>{mora.text().to_string()}</span> }
+                })
+                .collect_view()}
+            <span class=particle_class>"が"</span>
+            <span class="pitch-downstep-number">{format!("[{}]", accent.downstep())}</span>
+        </span>
+    }
+}
+
 #[component]
 fn KanjiCardContent(kanji: KanjiCard) -> impl IntoView {
     view! {
         <div class="kanji-flash-front">
             <div class="flash-character">{kanji.character}</div>
-            <div class="flash-stroke-count">{kanji.stroke_count}черт</div>
+            <div class="flash-stroke-count">{stroke_count_label(kanji.stroke_count)}</div>
         </div>
     }
 }
@@ -236,33 +289,38 @@ fn KanjiAnswerContent(kanji: KanjiCard) -> impl IntoView {
                 </div>
             </div>
 
-            <div class="answer-readings">
-                <div class="readings-section">
-                    <h6 class="readings-title">Onyomi:</h6>
-                    <div class="readings-list">
-                        {kanji
-                            .onyomi
-                            .iter()
-                            .map(|reading| {
-                                view! { <span class="reading-item">{reading.clone()}</span> }
-                            })
-                            .collect_view()}
-                    </div>
-                </div>
-
-                <div class="readings-section">
-                    <h6 class="readings-title">Kunyomi:</h6>
-                    <div class="readings-list">
-                        {kanji
-                            .kunyomi
-                            .iter()
-                            .map(|reading| {
-                                view! { <span class="reading-item">{reading.clone()}</span> }
-                            })
-                            .collect_view()}
-                    </div>
-                </div>
-            </div>
+            {(!kanji.onyomi.is_empty() || !kanji.kunyomi.is_empty())
+                .then(|| {
+                    view! {
+                        <div class="answer-readings">
+                            <div class="readings-section">
+                                <h6 class="readings-title">Onyomi:</h6>
+                                <div class="readings-list">
+                                    {kanji
+                                        .onyomi
+                                        .iter()
+                                        .map(|reading| {
+                                            view! { <span class="reading-item">{reading.clone()}</span> }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </div>
+
+                            <div class="readings-section">
+                                <h6 class="readings-title">Kunyomi:</h6>
+                                <div class="readings-list">
+                                    {kanji
+                                        .kunyomi
+                                        .iter()
+                                        .map(|reading| {
+                                            view! { <span class="reading-item">{reading.clone()}</span> }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            </div>
+                        </div>
+                    }
+                })}
 
             <div class="answer-radicals">
                 <h6 class="radicals-title">Радикалы:</h6>
@@ -287,6 +345,28 @@ fn KanjiAnswerContent(kanji: KanjiCard) -> impl IntoView {
     }
 }
 
+#[component]
+fn KanjiWritingCardContent(writing: KanjiWritingCard) -> impl IntoView {
+    view! {
+        <div class="kanji-writing-flash-front">
+            <div class="flash-meaning">{writing.meanings.join(", ")}</div>
+            <div class="flash-stroke-count">{stroke_count_label(writing.stroke_count)}</div>
+        </div>
+    }
+}
+
+#[component]
+fn KanjiWritingAnswerContent(writing: KanjiWritingCard) -> impl IntoView {
+    view! {
+        <div class="kanji-writing-flash-back">
+            <div class="answer-header">
+                <h4 class="answer-title">{writing.character}</h4>
+            </div>
+            <div class="stroke-order-animation" inner_html=writing.animation_svg></div>
+        </div>
+    }
+}
+
 #[component]
 fn GrammarCardContent(grammar: GrammarCard) -> impl IntoView {
     view! {
@@ -352,24 +432,101 @@ fn GrammarAnswerContent(grammar: GrammarCard) -> impl IntoView {
     }
 }
 
+#[component]
+fn SentenceCardContent(sentence: SentenceCard) -> impl IntoView {
+    view! {
+        <div class="sentence-flash-front">
+            <div class="flash-sentence">{sentence.japanese}</div>
+            <div class="flash-reading">{sentence.reading}</div>
+        </div>
+    }
+}
+
+#[component]
+fn SentenceAnswerContent(sentence: SentenceCard) -> impl IntoView {
+    view! {
+        <div class="sentence-flash-back">
+            <div class="answer-header">
+                <h4 class="answer-title">{sentence.japanese}</h4>
+                <span class="answer-reading">{sentence.reading}</span>
+            </div>
+
+            <div class="answer-translation">
+                <h5 class="translation-title">Перевод:</h5>
+                <p class="translation-text">{sentence.translation}</p>
+            </div>
+
+            {(!sentence.words.is_empty())
+                .then(|| {
+                    view! {
+                        <div class="answer-words">
+                            <h6 class="words-title">Слова:</h6>
+                            <div class="words-list">
+                                {sentence
+                                    .words
+                                    .iter()
+                                    .map(|word| {
+                                        view! { <span class="word-item">{word.clone()}</span> }
+                                    })
+                                    .collect_view()}
+                            </div>
+                        </div>
+                    }
+                })}
+        </div>
+    }
+}
+
+#[component]
+fn ClozeCardContent(cloze: ClozeCard) -> impl IntoView {
+    view! {
+        <div class="cloze-flash-front">
+            <div class="flash-sentence">{cloze.sentence}</div>
+            <div class="flash-translation">{cloze.translation}</div>
+        </div>
+    }
+}
+
+#[component]
+fn ClozeAnswerContent(cloze: ClozeCard) -> impl IntoView {
+    view! {
+        <div class="cloze-flash-back">
+            <div class="answer-header">
+                <h4 class="answer-title">{cloze.answer}</h4>
+                <span class="answer-reading">{cloze.word}</span>
+            </div>
+
+            <div class="answer-translation">
+                <h5 class="translation-title">Перевод:</h5>
+                <p class="translation-text">{cloze.translation}</p>
+            </div>
+        </div>
+    }
+}
+
 // Wrapper types for study session
 #[derive(Clone)]
 pub enum StudyCard {
     Vocab(VocabCard),
     Kanji(KanjiCard),
+    KanjiWriting(KanjiWritingCard),
     Grammar(GrammarCard),
+    Sentence(SentenceCard),
+    Cloze(ClozeCard),
 }
 
 #[derive(Clone)]
 pub struct StudyCardWrapper {
     pub card_id: ulid::Ulid,
     pub card: StudyCard,
+    pub variant: origa::domain::CardVariant,
 }
 
 #[derive(Clone)]
 pub struct VocabCard {
     pub japanese: String,
     pub reading: String,
+    pub pitch_accents: Vec<PitchAccent>,
     pub translation: String,
     pub examples: Vec<VocabExample>,
 }
@@ -391,6 +548,14 @@ pub struct KanjiCard {
     pub radicals: Vec<RadicalInfo>,
 }
 
+#[derive(Clone)]
+pub struct KanjiWritingCard {
+    pub character: String,
+    pub stroke_count: u8,
+    pub meanings: Vec<String>,
+    pub animation_svg: String,
+}
+
 #[derive(Clone)]
 pub struct RadicalInfo {
     pub character: String,
@@ -411,3 +576,19 @@ pub struct GrammarExample {
     pub sentence: String,
     pub translation: String,
 }
+
+#[derive(Clone)]
+pub struct SentenceCard {
+    pub japanese: String,
+    pub reading: String,
+    pub translation: String,
+    pub words: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct ClozeCard {
+    pub sentence: String,
+    pub answer: String,
+    pub word: String,
+    pub translation: String,
+}
@@ -75,6 +75,15 @@ pub fn StudySession() -> impl IntoView {
         }
     });
 
+    // Время показа текущей карточки для замера скорости ответа,
+    // отсчитывается с момента, когда карточка появилась на экране
+    let (card_shown_at, set_card_shown_at) = signal(chrono::Utc::now());
+    Effect::new(move |_| {
+        if current_card.with(|card| card.is_some()) {
+            set_card_shown_at.set(chrono::Utc::now());
+        }
+    });
+
     let handle_rate = {
         let study_service = study_service.clone();
         let current_card = current_card;
@@ -87,11 +96,20 @@ pub fn StudySession() -> impl IntoView {
             if let Some(card) = current_card.get() {
                 let service = study_service.clone();
                 let card_id = card.card_id;
+                let variant = card.variant;
+                let response_time = chrono::Utc::now() - card_shown_at.get();
                 let user_id = ulid::Ulid::new(); // TODO: получить реальный user_id
                 let is_fixation_local = is_fixation_signal.get();
                 spawn_local(async move {
                     let _ = service
-                        .rate_card(user_id, card_id, rating, is_fixation_local)
+                        .rate_card(
+                            user_id,
+                            card_id,
+                            rating,
+                            is_fixation_local,
+                            variant,
+                            Some(response_time),
+                        )
                         .await;
                 });
             }
@@ -1,12 +1,12 @@
 use crate::components::cards::kanji_card::RadicalInfo;
-use crate::components::cards::kanji_detail::{ExampleInfo, KanjiDetailData};
+use crate::components::cards::kanji_detail::{ExampleInfo, KanjiDetailData, ReadingInfo};
 use crate::components::cards::vocab_card::CardStatus;
 use leptos::prelude::*;
 use origa::application::{
     CreateKanjiCardUseCase, DeleteCardUseCase, KanjiInfoUseCase, KanjiListUseCase,
     KnowledgeSetCardsUseCase,
 };
-use origa::domain::{Card, JapaneseLevel, OrigaError, StudyCard};
+use origa::domain::{Card, JapaneseLevel, JapaneseText, OrigaError, StudyCard, kana_to_romaji};
 use origa::settings::ApplicationEnvironment;
 use std::collections::HashMap;
 use ulid::Ulid;
@@ -52,11 +52,11 @@ impl KanjiService {
                 KanjiListData {
                     id: format!("kanji_{}", index + 1),
                     character: kanji_char.clone(),
-                    stroke_count: 0, // Not available in KanjiInfo
+                    stroke_count: kanji_info.stroke_count(),
                     jlpt_level: *kanji_info.jlpt(),
                     meanings: vec![kanji_info.description().to_string()],
-                    onyomi: vec![], // Not available in KanjiInfo
-                    kunyomi: vec![], // Not available in KanjiInfo
+                    onyomi: kanji_info.onyomi().to_vec(),
+                    kunyomi: kanji_info.kunyomi().to_vec(),
                     radicals: kanji_info
                         .radicals()
                         .into_iter()
@@ -195,7 +195,7 @@ impl KanjiService {
         Ok(KanjiDetailData {
             id: format!("kanji_{}", kanji_char),
             character: kanji_char.clone(),
-            stroke_count: 0, // Not available in KanjiInfo
+            stroke_count: kanji_info.stroke_count(),
             grade_level: format!("JLPT {}", kanji_info.jlpt()),
             jlpt_level: *kanji_info.jlpt(),
             meanings: if meanings.is_empty() {
@@ -203,8 +203,16 @@ impl KanjiService {
             } else {
                 meanings
             },
-            onyomi: vec![],  // Not available in KanjiInfo
-            kunyomi: vec![], // Not available in KanjiInfo
+            onyomi: kanji_info
+                .onyomi()
+                .iter()
+                .map(|reading| self.reading_info(reading))
+                .collect(),
+            kunyomi: kanji_info
+                .kunyomi()
+                .iter()
+                .map(|reading| self.reading_info(reading))
+                .collect(),
             radicals,
             examples,
             status: self.determine_card_status(&kanji_char, &user_cards, &user_study_cards),
@@ -309,6 +317,19 @@ impl KanjiService {
         }
     }
 
+    /// Чтение KANJIDIC2 вида `た.べる`: окуригана отделена точкой,
+    /// дефис обозначает приставку или суффикс
+    fn reading_info(&self, reading: &str) -> ReadingInfo {
+        let reading = reading.trim_matches('-');
+        let (stem, okurigana) = reading.split_once('.').unwrap_or((reading, ""));
+
+        ReadingInfo {
+            romaji: kana_to_romaji(&format!("{}{}", stem, okurigana)),
+            hiragana: stem.to_hiragana(),
+            okurigana: (!okurigana.is_empty()).then(|| okurigana.to_string()),
+        }
+    }
+
     fn calculate_next_review(
         &self,
         kanji: &str,
@@ -1,5 +1,6 @@
 use crate::components::interactive::flash_card::{
-    GrammarCard, KanjiCard, StudyCard, StudyCardWrapper, VocabCard, VocabExample,
+    ClozeCard, GrammarCard, KanjiCard, KanjiWritingCard, SentenceCard, StudyCard, StudyCardWrapper,
+    VocabCard, VocabExample,
 };
 use chrono::Duration;
 use origa::application::srs_service::RateMode;
@@ -7,7 +8,9 @@ use origa::application::{
     CompleteLessonUseCase, KnowledgeSetCardsUseCase, RateCardUseCase, SelectCardsToFixationUseCase,
     SelectCardsToLessonUseCase,
 };
-use origa::domain::{Card, OrigaError, Rating, StudyCard as DomainStudyCard};
+use origa::domain::{
+    Card, CardVariant, JapaneseText, LessonCard, OrigaError, Rating, StudyCard as DomainStudyCard,
+};
 use origa::settings::ApplicationEnvironment;
 use ulid::Ulid;
 
@@ -26,14 +29,14 @@ impl StudyService {
     ) -> Result<Vec<StudyCardWrapper>, OrigaError> {
         let repository = ApplicationEnvironment::get().get_repository().await?;
         let use_case = SelectCardsToLessonUseCase::new(repository);
-        let cards_map = use_case.execute(user_id).await?;
+        let cards = use_case.execute(user_id).await?;
 
         // Получить все StudyCard для доступа к memory_history
         let knowledge_use_case = KnowledgeSetCardsUseCase::new(repository);
         let all_study_cards = knowledge_use_case.execute(user_id).await?;
 
         // Конвертировать в StudyCardWrapper
-        self.convert_cards_to_wrappers(cards_map, &all_study_cards)
+        self.convert_cards_to_wrappers(cards, &all_study_cards)
     }
 
     /// Получить карточки для закрепления
@@ -43,14 +46,14 @@ impl StudyService {
     ) -> Result<Vec<StudyCardWrapper>, OrigaError> {
         let repository = ApplicationEnvironment::get().get_repository().await?;
         let use_case = SelectCardsToFixationUseCase::new(repository);
-        let cards_map = use_case.execute(user_id).await?;
+        let cards = use_case.execute(user_id).await?;
 
         // Получить все StudyCard для доступа к memory_history
         let knowledge_use_case = KnowledgeSetCardsUseCase::new(repository);
         let all_study_cards = knowledge_use_case.execute(user_id).await?;
 
         // Конвертировать в StudyCardWrapper
-        self.convert_cards_to_wrappers(cards_map, &all_study_cards)
+        self.convert_cards_to_wrappers(cards, &all_study_cards)
     }
 
     /// Оценить карточку
@@ -60,6 +63,8 @@ impl StudyService {
         card_id: Ulid,
         rating: Rating,
         is_fixation: bool,
+        variant: CardVariant,
+        response_time: Option<Duration>,
     ) -> Result<(), OrigaError> {
         let repository = ApplicationEnvironment::get().get_repository().await?;
         let srs_service = ApplicationEnvironment::get().get_srs_service().await?;
@@ -69,7 +74,9 @@ impl StudyService {
             RateMode::StandardLesson
         };
         let use_case = RateCardUseCase::new(repository, srs_service);
-        use_case.execute(user_id, card_id, mode, rating).await
+        use_case
+            .execute(user_id, card_id, mode, rating, variant, response_time)
+            .await
     }
 
     /// Завершить урок
@@ -84,20 +91,26 @@ impl StudyService {
         use_case.execute(user_id, duration).await
     }
 
-    /// Конвертировать HashMap<Ulid, Card> в Vec<StudyCardWrapper>
+    /// Конвертировать Vec<LessonCard> в Vec<StudyCardWrapper>
     fn convert_cards_to_wrappers(
         &self,
-        cards_map: std::collections::HashMap<Ulid, Card>,
+        cards: Vec<LessonCard>,
         all_study_cards: &[DomainStudyCard],
     ) -> Result<Vec<StudyCardWrapper>, OrigaError> {
         let mut wrappers = Vec::new();
 
-        for (card_id, card) in cards_map {
+        for lesson_card in cards {
+            let card_id = *lesson_card.card_id();
             // Найти соответствующий StudyCard для получения memory_history
             let study_card = all_study_cards.iter().find(|sc| sc.card_id() == &card_id);
 
             // Конвертировать Card в StudyCardWrapper
-            let wrapper = self.convert_card_to_wrapper(card_id, &card, study_card);
+            let wrapper = self.convert_card_to_wrapper(
+                card_id,
+                lesson_card.card(),
+                lesson_card.variant(),
+                study_card,
+            );
             wrappers.push(wrapper);
         }
 
@@ -108,32 +121,27 @@ impl StudyService {
         &self,
         card_id: Ulid,
         card: &Card,
+        variant: CardVariant,
         _study_card: Option<&DomainStudyCard>,
     ) -> StudyCardWrapper {
         match card {
             Card::Vocabulary(vocab) => {
                 // Получить reading через tokenizer
-                let reading = origa::domain::tokenize_text(vocab.word().text())
-                    .ok()
-                    .and_then(|tokens| tokens.first().cloned())
-                    .map(|token| token.phonological_surface_form().to_string())
-                    .unwrap_or_default();
+                let reading = vocab.word().text().as_reading().unwrap_or_default();
 
                 StudyCardWrapper {
                     card_id,
+                    variant,
                     card: StudyCard::Vocab(VocabCard {
                         japanese: vocab.word().text().to_string(),
                         reading,
+                        pitch_accents: vocab.pitch_accents(),
                         translation: vocab.meaning().text().to_string(),
                         examples: vocab
                             .example_phrases()
                             .iter()
                             .map(|ex| {
-                                let ex_reading = origa::domain::tokenize_text(ex.text())
-                                    .ok()
-                                    .and_then(|tokens| tokens.first().cloned())
-                                    .map(|token| token.phonological_surface_form().to_string())
-                                    .unwrap_or_default();
+                                let ex_reading = ex.text().as_reading().unwrap_or_default();
                                 VocabExample {
                                     japanese: ex.text().to_string(),
                                     reading: ex_reading,
@@ -144,19 +152,39 @@ impl StudyService {
                     }),
                 }
             }
+            Card::Kanji(kanji) if variant == CardVariant::Writing => {
+                let stroke_order = kanji.stroke_order().ok();
+                StudyCardWrapper {
+                    card_id,
+                    variant,
+                    card: StudyCard::KanjiWriting(KanjiWritingCard {
+                        character: kanji.kanji().text().to_string(),
+                        stroke_count: stroke_order
+                            .as_ref()
+                            .map(|stroke_order| stroke_order.stroke_count() as u8)
+                            .unwrap_or_default(),
+                        meanings: vec![kanji.description().text().to_string()],
+                        animation_svg: stroke_order
+                            .map(|stroke_order| stroke_order.animation_svg().to_string())
+                            .unwrap_or_default(),
+                    }),
+                }
+            }
             Card::Kanji(kanji) => StudyCardWrapper {
                 card_id,
+                variant,
                 card: StudyCard::Kanji(KanjiCard {
                     character: kanji.kanji().text().to_string(),
-                    stroke_count: 0, // Not available
+                    stroke_count: kanji.stroke_count(),
                     meanings: vec![kanji.description().text().to_string()],
-                    onyomi: vec![],   // Not available
-                    kunyomi: vec![],  // Not available
+                    onyomi: kanji.onyomi(),
+                    kunyomi: kanji.kunyomi(),
                     radicals: vec![], // Not available
                 }),
             },
             Card::Grammar(grammar) => StudyCardWrapper {
                 card_id,
+                variant,
                 card: StudyCard::Grammar(GrammarCard {
                     pattern: grammar.title().text().to_string(),
                     meaning: grammar.description().text().to_string(),
@@ -169,6 +197,26 @@ impl StudyService {
                     examples: vec![], // Not available in GrammarRuleCard
                 }),
             },
+            Card::Sentence(sentence) => StudyCardWrapper {
+                card_id,
+                variant,
+                card: StudyCard::Sentence(SentenceCard {
+                    japanese: sentence.sentence().text().to_string(),
+                    reading: sentence.sentence().text().as_reading().unwrap_or_default(),
+                    translation: sentence.translation().text().to_string(),
+                    words: sentence.words().to_vec(),
+                }),
+            },
+            Card::Cloze(cloze) => StudyCardWrapper {
+                card_id,
+                variant,
+                card: StudyCard::Cloze(ClozeCard {
+                    sentence: cloze.sentence().text().to_string(),
+                    answer: cloze.answer().text().to_string(),
+                    word: cloze.word().to_string(),
+                    translation: cloze.translation().to_string(),
+                }),
+            },
         }
     }
 }
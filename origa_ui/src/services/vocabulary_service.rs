@@ -2,8 +2,7 @@ use crate::components::cards::vocab_card::{CardStatus, VocabularyCardData};
 use origa::application::{
     CreateVocabularyCardUseCase, DeleteCardUseCase, KnowledgeSetCardsUseCase,
 };
-use origa::domain::tokenize_text;
-use origa::domain::{Card, OrigaError, StudyCard};
+use origa::domain::{Card, JapaneseText, OrigaError, StudyCard};
 use origa::settings::ApplicationEnvironment;
 use ulid::Ulid;
 
@@ -95,11 +94,7 @@ impl VocabularyService {
             let japanese = vocab.word().text().to_string();
 
             // Получить reading через tokenizer
-            let reading = tokenize_text(&japanese)
-                .ok()
-                .and_then(|tokens| tokens.first().cloned())
-                .map(|token| token.phonological_surface_form().to_string())
-                .unwrap_or_default();
+            let reading = japanese.as_reading().unwrap_or_default();
 
             let translation = vocab.meaning().text().to_string();
 
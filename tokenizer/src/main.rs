@@ -1,5 +1,5 @@
-use clap::Parser;
-use origa::domain::tokenize_text;
+use clap::{Parser, ValueEnum};
+use origa::domain::{FuriganaFormat, furiganize_text, render_furigana, tokenize_text};
 use std::collections::HashSet;
 use std::path::Path;
 
@@ -13,6 +13,41 @@ struct Cli {
     /// Читать текст из файла
     #[arg(short, long)]
     file: bool,
+
+    /// Вывести текст с фуриганой вместо списка слов
+    #[arg(long, value_enum)]
+    furigana: Option<FuriganaOutput>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FuriganaOutput {
+    Html,
+    Anki,
+    Reading,
+    Markdown,
+}
+
+impl From<FuriganaOutput> for FuriganaFormat {
+    fn from(output: FuriganaOutput) -> Self {
+        match output {
+            FuriganaOutput::Html => FuriganaFormat::Html,
+            FuriganaOutput::Anki => FuriganaFormat::Anki,
+            FuriganaOutput::Reading => FuriganaFormat::Reading,
+            FuriganaOutput::Markdown => FuriganaFormat::Markdown,
+        }
+    }
+}
+
+fn print_furigana(text: &str, format: FuriganaFormat) {
+    for line in text.lines() {
+        match furiganize_text(line) {
+            Ok(segments) => println!("{}", render_furigana(&segments, format)),
+            Err(e) => {
+                eprintln!("Ошибка токенизации: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
 }
 
 fn main() {
@@ -28,6 +63,11 @@ fn main() {
 
         let text = String::from_utf8_lossy(&bytes);
 
+        if let Some(format) = cli.furigana {
+            print_furigana(&text, format.into());
+            return;
+        }
+
         for line in text.lines() {
             let result = tokenize_text(line).map(|tokens| {
                 for token in tokens {
@@ -42,6 +82,9 @@ fn main() {
                 std::process::exit(1);
             }
         }
+    } else if let Some(format) = cli.furigana {
+        print_furigana(&cli.text, format.into());
+        return;
     } else {
         match tokenize_text(&cli.text) {
             Ok(tokens) => {